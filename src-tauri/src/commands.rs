@@ -3,7 +3,7 @@
 
 use crate::config::controller_config::ControllerConfig;
 use crate::config::keymap_config::KeymapConfig;
-use crate::config::mouse_config::MouseConfig;
+use crate::config::mouse_config::{AccelerationCurve, MouseConfig};
 use crate::state::AppState;
 use anyhow::Result;
 use log::{error, info};
@@ -247,6 +247,21 @@ pub async fn start_gyro_calibration(
         .map_err(|e| e.to_string())
 }
 
+/// Starts the point-at-corners screen calibration for the absolute air mouse.
+///
+/// # Returns
+/// Nothing, but emits events while the wizard runs:
+/// - "screen-calibration-step" with the translation key of the corner to point at next
+/// - "screen-calibration-finished" with whether a calibration was fitted and saved
+#[tauri::command]
+pub async fn start_screen_calibration(app_state: State<'_, AppState>) -> Result<(), String> {
+    let mouse_sender_arc = app_state.mouse_sender.clone();
+    let mouse_sender_guard = mouse_sender_arc.lock().await;
+
+    mouse_sender_guard.start_screen_calibration().await;
+    Ok(())
+}
+
 /// Gets the current controller configuration.
 #[tauri::command]
 pub async fn get_controller_config(
@@ -385,6 +400,29 @@ pub async fn reset_mouse_config(
     Ok(new_config)
 }
 
+/// Most points `get_acceleration_curve_samples` returns; more than enough to plot a curve smoothly.
+const MAX_CURVE_SAMPLES: usize = 1000;
+
+/// Samples an acceleration curve so the frontend can plot it.
+///
+/// # Returns
+/// `count` `[speed, gain]` points evenly spaced from 0 to `max_speed`.
+/// `count` may be at most `MAX_CURVE_SAMPLES`.
+#[tauri::command]
+pub async fn get_acceleration_curve_samples(
+    curve: AccelerationCurve,
+    max_speed: f32,
+    count: usize,
+) -> Result<Vec<[f32; 2]>, String> {
+    if !max_speed.is_finite() || max_speed <= 0.0 {
+        return Err("max_speed must be a positive number".to_string());
+    }
+    if count > MAX_CURVE_SAMPLES {
+        return Err(format!("count must be at most {}", MAX_CURVE_SAMPLES));
+    }
+    Ok(curve.samples(max_speed, count))
+}
+
 // --- KeymapConfig Commands ---
 
 #[tauri::command]
@@ -446,6 +484,24 @@ pub async fn reset_keymap_config(
     Ok(new_config)
 }
 
+// --- Input Pause Commands ---
+
+/// Gets whether input injection is currently paused.
+#[tauri::command]
+pub async fn get_input_paused(app_state: State<'_, AppState>) -> Result<bool, String> {
+    Ok(app_state.is_input_paused())
+}
+
+/// Pauses or resumes input injection. Emits "input-paused" once the mapper has applied it.
+#[tauri::command]
+pub async fn set_input_paused(app_state: State<'_, AppState>, paused: bool) -> Result<(), String> {
+    let mouse_sender_arc = app_state.mouse_sender.clone();
+    let mouse_sender_guard = mouse_sender_arc.lock().await;
+
+    mouse_sender_guard.set_paused(paused).await;
+    Ok(())
+}
+
 #[macro_export]
 macro_rules! export_commands {
     () => {
@@ -460,15 +516,19 @@ macro_rules! export_commands {
             $crate::commands::initialize_controller,
             $crate::commands::start_mag_calibration_wizard,
             $crate::commands::start_gyro_calibration,
+            $crate::commands::start_screen_calibration,
             $crate::commands::get_controller_config,
             $crate::commands::set_controller_config,
             $crate::commands::reset_controller_config,
             $crate::commands::get_mouse_config,
             $crate::commands::set_mouse_config,
             $crate::commands::reset_mouse_config,
+            $crate::commands::get_acceleration_curve_samples,
             $crate::commands::get_keymap_config,
             $crate::commands::set_keymap_config,
             $crate::commands::reset_keymap_config,
+            $crate::commands::get_input_paused,
+            $crate::commands::set_input_paused,
             $crate::commands::get_connection_status,
             $crate::commands::get_current_language,
             $crate::commands::set_current_language
@@ -6,6 +6,8 @@ use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Manager};
 use tokio::fs;
 
+use crate::core::tremor_filter::TremorSuppression;
+
 const CONFIG_FILE_NAME: &str = "controller_config.json";
 // 定义磁力计校准参数结构体
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -46,6 +48,11 @@ pub struct ControllerConfig {
     /// 控制姿态的平滑程度。值越小平滑越强，延迟越大；值越大延迟越小，但可能抖动。
     pub orientation_smoothing_factor: f64,
 
+    /// 手部震颤抑制。在姿态解算前对角速度进行带阻滤波或加权移动平均，
+    /// 抑制 4–12 Hz 的生理性震颤。
+    #[serde(default)]
+    pub tremor_suppression: TremorSuppression,
+
     /// 地区地磁强度 (uT)
     pub local_earth_mag_field: f64,
 
@@ -64,6 +71,7 @@ impl Default for ControllerConfig {
             delta_t_smoothing_alpha: 1.0,
             madgwick_beta: 0.08,
             orientation_smoothing_factor: 0.8, // Default smoothing factor
+            tremor_suppression: TremorSuppression::Off,
             local_earth_mag_field,
             mag_calibration: MagCalibration::default(),
             gyro_calibration: GyroCalibration::default(),
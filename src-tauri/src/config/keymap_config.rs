@@ -5,10 +5,275 @@ use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Manager};
 use tokio::fs;
 
+use crate::core::controller::ControllerButton;
+
 const CONFIG_FILE_NAME: &str = "keymap_config.json";
 
+/// How a binding reacts while its button is held
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+#[serde(tag = "type")]
+pub enum ButtonBehavior {
+    /// The bound key is pressed and released together with the button
+    #[default]
+    Normal,
+    /// The bound key is re-fired while the button is held
+    Repeat {
+        /// Delay before the first repeat, in milliseconds
+        delay_ms: u64,
+        /// Repeats per second after the initial delay
+        rate_hz: f32,
+    },
+    /// The first press holds the bound key, the second press releases it
+    Toggle,
+    /// The bound key is clicked repeatedly while the button is held
+    Turbo {
+        /// Clicks per second
+        rate_hz: f32,
+    },
+}
+
+/// Controller gesture that cycles through the enabled mouse modes.
+/// The gesture button's own binding only fires once the gesture has been ruled out.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ModeCycleGesture {
+    /// Mode cycling from the controller is disabled
+    Disabled,
+    /// Double-click the button within `window_ms`
+    DoubleClick {
+        button: ControllerButton,
+        window_ms: u64,
+    },
+    /// Hold the button for at least `hold_ms`
+    Hold {
+        button: ControllerButton,
+        hold_ms: u64,
+    },
+}
+
+impl ModeCycleGesture {
+    /// Returns the button the gesture is performed on, if enabled
+    pub fn button(&self) -> Option<ControllerButton> {
+        match *self {
+            ModeCycleGesture::Disabled => None,
+            ModeCycleGesture::DoubleClick { button, .. } => Some(button),
+            ModeCycleGesture::Hold { button, .. } => Some(button),
+        }
+    }
+}
+
+impl Default for ModeCycleGesture {
+    fn default() -> Self {
+        ModeCycleGesture::DoubleClick {
+            button: ControllerButton::Home,
+            window_ms: 300,
+        }
+    }
+}
+
+/// Direction the controller is tilted in, relative to where tilt keys mode was entered
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TiltDirection {
+    /// Rolled to the left
+    Left,
+    /// Rolled to the right
+    Right,
+    /// Tip tilted down
+    Forward,
+    /// Tip tilted up
+    Backward,
+}
+
+impl TiltDirection {
+    /// All tilt directions, in `index` order
+    pub const ALL: [TiltDirection; 4] = [
+        TiltDirection::Left,
+        TiltDirection::Right,
+        TiltDirection::Forward,
+        TiltDirection::Backward,
+    ];
+
+    /// Returns a stable index for per-direction state arrays
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Keys pressed by tilting the controller in tilt keys mode
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TiltKeyBindings {
+    pub left: Option<String>,
+    pub right: Option<String>,
+    pub forward: Option<String>,
+    pub backward: Option<String>,
+}
+
+impl Default for TiltKeyBindings {
+    fn default() -> Self {
+        TiltKeyBindings {
+            left: Some("A".to_string()),
+            right: Some("D".to_string()),
+            forward: Some("W".to_string()),
+            backward: Some("S".to_string()),
+        }
+    }
+}
+
+impl TiltKeyBindings {
+    /// Returns the key string bound to the given tilt direction, if any
+    pub fn binding(&self, direction: TiltDirection) -> Option<&str> {
+        match direction {
+            TiltDirection::Left => self.left.as_deref(),
+            TiltDirection::Right => self.right.as_deref(),
+            TiltDirection::Forward => self.forward.as_deref(),
+            TiltDirection::Backward => self.backward.as_deref(),
+        }
+    }
+}
+
+/// A button or touchpad quadrant that can be part of a typing chord
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ChordKey {
+    Trigger,
+    Back,
+    VolumeUp,
+    VolumeDown,
+    /// Touchpad clicked in its upper quadrant
+    TouchpadUp,
+    /// Touchpad clicked in its lower quadrant
+    TouchpadDown,
+    /// Touchpad clicked in its left quadrant
+    TouchpadLeft,
+    /// Touchpad clicked in its right quadrant
+    TouchpadRight,
+}
+
+/// A chord and the binding it types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChordBinding {
+    /// Keys pressed together, in any order
+    pub keys: Vec<ChordKey>,
+    /// Binding string to type, e.g. "a", "Space" or "Backspace"
+    pub output: String,
+}
+
+/// The built-in chord layout. Single buttons and button pairs type the most frequent letters,
+/// a touchpad quadrant adds the rest, the lower quadrant types digits.
+fn default_chord_layout() -> Vec<ChordBinding> {
+    use ChordKey::*;
+    let layout: &[(&[ChordKey], &str)] = &[
+        (&[Trigger], "e"),
+        (&[Back], "t"),
+        (&[VolumeUp], "a"),
+        (&[VolumeDown], "o"),
+        (&[Trigger, Back], "i"),
+        (&[Trigger, VolumeUp], "n"),
+        (&[Trigger, VolumeDown], "s"),
+        (&[Back, VolumeUp], "h"),
+        (&[Back, VolumeDown], "r"),
+        (&[VolumeUp, VolumeDown], "d"),
+        (&[Trigger, Back, VolumeUp], "l"),
+        (&[Trigger, Back, VolumeDown], "c"),
+        (&[Trigger, VolumeUp, VolumeDown], "u"),
+        (&[Back, VolumeUp, VolumeDown], "m"),
+        (&[Trigger, Back, VolumeUp, VolumeDown], "Esc"),
+        (&[TouchpadUp], "Tab"),
+        (&[TouchpadDown], "Enter"),
+        (&[TouchpadLeft], "Backspace"),
+        (&[TouchpadRight], "Space"),
+        (&[TouchpadUp, Trigger], "w"),
+        (&[TouchpadUp, Back], "f"),
+        (&[TouchpadUp, VolumeUp], "g"),
+        (&[TouchpadUp, VolumeDown], "y"),
+        (&[TouchpadRight, Trigger], "p"),
+        (&[TouchpadRight, Back], "b"),
+        (&[TouchpadRight, VolumeUp], "v"),
+        (&[TouchpadRight, VolumeDown], "k"),
+        (&[TouchpadLeft, Trigger], "j"),
+        (&[TouchpadLeft, Back], "x"),
+        (&[TouchpadLeft, VolumeUp], "q"),
+        (&[TouchpadLeft, VolumeDown], "z"),
+        (&[TouchpadDown, Trigger], "1"),
+        (&[TouchpadDown, Back], "2"),
+        (&[TouchpadDown, VolumeUp], "3"),
+        (&[TouchpadDown, VolumeDown], "4"),
+        (&[TouchpadDown, Trigger, Back], "5"),
+        (&[TouchpadDown, Trigger, VolumeUp], "6"),
+        (&[TouchpadDown, Trigger, VolumeDown], "7"),
+        (&[TouchpadDown, Back, VolumeUp], "8"),
+        (&[TouchpadDown, Back, VolumeDown], "9"),
+        (&[TouchpadDown, VolumeUp, VolumeDown], "0"),
+    ];
+    layout
+        .iter()
+        .map(|(keys, output)| ChordBinding {
+            keys: keys.to_vec(),
+            output: output.to_string(),
+        })
+        .collect()
+}
+
+/// Radial menu settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RadialMenuConfig {
+    /// Button that opens the menu while held; releasing it runs the highlighted slice.
+    /// `None` disables the menu.
+    pub button: Option<ControllerButton>,
+    /// Bindings of the slices, clockwise from the top
+    pub slices: Vec<String>,
+    /// Distance from the touchpad centre, as a share of its radius, needed to highlight a slice
+    pub touchpad_deadzone: f32,
+    /// Tilt (degrees) from where the menu was opened needed to highlight a slice
+    pub tilt_deadzone: f32,
+}
+
+impl Default for RadialMenuConfig {
+    fn default() -> Self {
+        RadialMenuConfig {
+            button: None,
+            slices: vec![
+                "Ctrl+C".to_string(),
+                "Ctrl+V".to_string(),
+                "Ctrl+Z".to_string(),
+                "Esc".to_string(),
+            ],
+            touchpad_deadzone: 0.4,
+            tilt_deadzone: 10.0,
+        }
+    }
+}
+
+/// Per-button behaviour settings
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct ButtonBehaviors {
+    pub trigger: ButtonBehavior,
+    pub home: ButtonBehavior,
+    pub back: ButtonBehavior,
+    pub volume_up: ButtonBehavior,
+    pub volume_down: ButtonBehavior,
+    pub touchpad: ButtonBehavior,
+}
+
+impl ButtonBehaviors {
+    /// Returns the behaviour configured for the given button
+    pub fn get(&self, button: ControllerButton) -> ButtonBehavior {
+        match button {
+            ControllerButton::Trigger => self.trigger,
+            ControllerButton::Home => self.home,
+            ControllerButton::Back => self.back,
+            ControllerButton::VolumeUp => self.volume_up,
+            ControllerButton::VolumeDown => self.volume_down,
+            ControllerButton::Touchpad => self.touchpad,
+        }
+    }
+}
+
 /// Configuration for button mappings
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct KeymapConfig {
     /// Trigger button mapping
     pub trigger: Option<String>,
@@ -22,6 +287,22 @@ pub struct KeymapConfig {
     pub volume_down: Option<String>,
     /// Touchpad click mapping
     pub touchpad: Option<String>,
+    /// Per-button repeat, toggle and turbo behaviour
+    pub behaviors: ButtonBehaviors,
+    /// Buttons that must be held together to pause or resume input injection.
+    /// Empty by default, which disables the gesture; chord buttons' presses are held back briefly
+    /// while the chord may be forming, so it is opt-in.
+    pub pause_chord: Vec<ControllerButton>,
+    /// How long the pause chord must be held, in milliseconds
+    pub pause_hold_ms: u64,
+    /// Gesture that cycles through `MouseConfig::enabled_modes`
+    pub mode_cycle: ModeCycleGesture,
+    /// Keys pressed by tilting the controller in tilt keys mode
+    pub tilt_keys: TiltKeyBindings,
+    /// Radial menu opened by holding a button
+    pub radial_menu: RadialMenuConfig,
+    /// User chords for chord typing mode. They take precedence over the built-in layout.
+    pub chords: Vec<ChordBinding>,
 }
 
 impl Default for KeymapConfig {
@@ -33,11 +314,47 @@ impl Default for KeymapConfig {
             volume_up: Some("Volume up".to_string()),
             volume_down: Some("Volume down".to_string()),
             touchpad: Some("Right".to_string()),
+            behaviors: ButtonBehaviors::default(),
+            pause_chord: Vec::new(),
+            pause_hold_ms: 1000,
+            mode_cycle: ModeCycleGesture::default(),
+            tilt_keys: TiltKeyBindings::default(),
+            radial_menu: RadialMenuConfig::default(),
+            chords: Vec::new(),
         }
     }
 }
 
 impl KeymapConfig {
+    /// Returns the key string bound to the given button, if any
+    pub fn binding(&self, button: ControllerButton) -> Option<&str> {
+        match button {
+            ControllerButton::Trigger => self.trigger.as_deref(),
+            ControllerButton::Home => self.home.as_deref(),
+            ControllerButton::Back => self.back.as_deref(),
+            ControllerButton::VolumeUp => self.volume_up.as_deref(),
+            ControllerButton::VolumeDown => self.volume_down.as_deref(),
+            ControllerButton::Touchpad => self.touchpad.as_deref(),
+        }
+    }
+
+    /// Returns the binding typed by a chord, checking user chords before the built-in layout
+    pub fn chord_output(&self, keys: &[ChordKey]) -> Option<String> {
+        let normalize = |keys: &[ChordKey]| {
+            let mut keys = keys.to_vec();
+            keys.sort();
+            keys.dedup();
+            keys
+        };
+        let chord = normalize(keys);
+        self.chords
+            .iter()
+            .cloned()
+            .chain(default_chord_layout())
+            .find(|binding| normalize(&binding.keys) == chord)
+            .map(|binding| binding.output)
+    }
+
     /// Loads the config from a configuration file.
     pub async fn load_config(app_handle: &AppHandle) -> Result<Self> {
         let config_dir = app_handle.path().app_config_dir()?;
@@ -1,10 +1,13 @@
 use crate::utils::ensure_directory_exists;
 use anyhow::Result;
 use log::{error, info, warn};
+use nalgebra::Matrix3;
 use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Manager};
 use tokio::fs;
 
+use crate::core::controller::ControllerButton;
+
 const CONFIG_FILE_NAME: &str = "mouse_config.json";
 
 /// Mouse movement mode
@@ -14,17 +17,429 @@ pub enum MouseMode {
     AirMouse,
     /// Use touchpad to control mouse movement (like laptop touchpad)
     Touchpad,
+    /// Use controller angular velocity for relative mouse movement (like a Wii/Switch pointer)
+    GyroMouse,
+    /// Press keys by tilting the controller (for driving and platform games)
+    TiltKeys,
+    /// Turn a game camera with raw relative mouse deltas from the gyro (for FPS games)
+    GyroAim,
+    /// Type characters drawn as single strokes on the touchpad
+    Handwriting,
+    /// Type by pressing chords of buttons and touchpad quadrants
+    Chord,
+    /// Single-switch scanning: a highlight cycles through actions and a button runs the highlighted one
+    Scanning,
+}
+
+/// Direction the pointer glides in when a scanning move action is selected
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ScanDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// An action offered by switch-access scanning
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ScanAction {
+    /// Glide the pointer until the switch is pressed again
+    Move { direction: ScanDirection },
+    /// Click a binding, e.g. "Left", "Right" or "Ctrl+C"
+    Press { binding: String },
+    /// Scan a nested list of actions
+    Layer {
+        label: String,
+        actions: Vec<ScanAction>,
+    },
+    /// Return from a layer to the list it was entered from
+    Back,
+}
+
+impl ScanAction {
+    /// Returns the text shown for the action while it is highlighted
+    pub fn label(&self) -> String {
+        match self {
+            ScanAction::Move { direction } => format!("Move {:?}", direction).to_lowercase(),
+            ScanAction::Press { binding } => binding.clone(),
+            ScanAction::Layer { label, .. } => label.clone(),
+            ScanAction::Back => "Back".to_string(),
+        }
+    }
+}
+
+/// Switch-access scanning settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScanningConfig {
+    /// The switch that runs the highlighted action. Other buttons keep their bindings.
+    pub button: ControllerButton,
+    /// How long (ms) each action stays highlighted.
+    pub interval_ms: u64,
+    /// How fast (pixels per second) the pointer glides for move actions.
+    pub pointer_speed: f32,
+    /// Actions cycled through, in order.
+    pub actions: Vec<ScanAction>,
+}
+
+impl Default for ScanningConfig {
+    fn default() -> Self {
+        let press = |binding: &str| ScanAction::Press {
+            binding: binding.to_string(),
+        };
+        ScanningConfig {
+            button: ControllerButton::Trigger,
+            interval_ms: 1000,
+            pointer_speed: 300.0,
+            actions: vec![
+                ScanAction::Move {
+                    direction: ScanDirection::Up,
+                },
+                ScanAction::Move {
+                    direction: ScanDirection::Down,
+                },
+                ScanAction::Move {
+                    direction: ScanDirection::Left,
+                },
+                ScanAction::Move {
+                    direction: ScanDirection::Right,
+                },
+                press("Left"),
+                press("Right"),
+                ScanAction::Layer {
+                    label: "Keys".to_string(),
+                    actions: vec![
+                        press("Enter"),
+                        press("Space"),
+                        press("Backspace"),
+                        press("Esc"),
+                        ScanAction::Back,
+                    ],
+                },
+            ],
+        }
+    }
+}
+
+/// A stroke shape and the binding typed when it is drawn
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnistrokeTemplate {
+    /// Binding string to type, e.g. "a", "Space" or "Backspace"
+    pub output: String,
+    /// Stroke points in drawing order, in touchpad coordinates (x right, y down)
+    pub points: Vec<[f32; 2]>,
+}
+
+/// Touchpad handwriting settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HandwritingConfig {
+    /// Extra stroke templates, matched alongside the built-in alphabet.
+    pub templates: Vec<UnistrokeTemplate>,
+    /// Lowest match score (0.0 to 1.0) that is typed. Worse matches are only reported.
+    pub min_score: f32,
+    /// Shortest stroke, in touchpad widths, that is recognized. Shorter touches are ignored as taps.
+    pub min_stroke_length: f32,
+}
+
+impl Default for HandwritingConfig {
+    fn default() -> Self {
+        HandwritingConfig {
+            templates: Vec::new(),
+            min_score: 0.7,
+            min_stroke_length: 0.15,
+        }
+    }
+}
+
+/// What tilt scrolling measures
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum TiltScrollInput {
+    /// How fast the controller turns; scrolling stops when it is held still
+    #[default]
+    Rate,
+    /// How far the controller is turned from where the button was pressed, like a joystick
+    Angle,
+}
+
+/// What twisting the controller does while tilt scrolling
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum TwistAction {
+    /// Twisting is ignored
+    None,
+    /// Ctrl+scroll, which zooms in most applications
+    #[default]
+    Zoom,
+    /// Horizontal scroll
+    HorizontalScroll,
+}
+
+/// Tilt scroll settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TiltScrollConfig {
+    /// Button that scrolls by tilting the controller while held. `None` disables tilt scrolling.
+    pub button: Option<ControllerButton>,
+    /// Whether scroll speed follows the rotation rate or the angle from where the button was pressed.
+    pub input: TiltScrollInput,
+    /// What twisting (roll) does.
+    pub twist: TwistAction,
+    /// Rotation ignored on each axis, in degrees per second (rate input) or degrees (angle input).
+    pub deadzone: f32,
+    /// Wheel steps per second for one unit of rotation past the deadzone.
+    pub sensitivity: f32,
+    /// Exponent applied to the rotation past the deadzone. 1.0 is linear; larger values speed up large movements.
+    pub acceleration: f32,
+}
+
+impl Default for TiltScrollConfig {
+    fn default() -> Self {
+        TiltScrollConfig {
+            button: None,
+            input: TiltScrollInput::Rate,
+            twist: TwistAction::Zoom,
+            deadzone: 3.0,
+            sensitivity: 0.1,
+            acceleration: 1.2,
+        }
+    }
+}
+
+/// Gyro aiming settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GyroAimConfig {
+    /// Degrees the camera turns per degree the controller turns.
+    pub sensitivity: f32,
+    /// Mouse counts that turn the game camera by one degree.
+    /// For Source-engine games this is 1 / (in-game sensitivity × 0.022).
+    pub counts_per_degree: f32,
+    /// Rotational speed (degrees per second) below which gyro output is suppressed.
+    pub deadzone: f32,
+    /// Button that pauses gyro output while held, so the controller can be repositioned.
+    pub ratchet_button: Option<ControllerButton>,
+    /// Whether the touchpad acts as a flick stick: touching near the edge turns the camera
+    /// towards the finger's direction, and circling the finger keeps turning.
+    pub flick_stick: bool,
+    /// Distance from the touchpad centre, as a share of its radius, beyond which the flick stick engages.
+    pub flick_deadzone: f32,
+    /// How long (ms) a flick takes to turn the camera.
+    pub flick_time_ms: u64,
+}
+
+impl Default for GyroAimConfig {
+    fn default() -> Self {
+        GyroAimConfig {
+            sensitivity: 1.5,
+            counts_per_degree: 45.45,
+            deadzone: 1.0,
+            ratchet_button: Some(ControllerButton::Back),
+            flick_stick: true,
+            flick_deadzone: 0.6,
+            flick_time_ms: 100,
+        }
+    }
+}
+
+/// Tilt-to-keys settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TiltKeysConfig {
+    /// Tilt (degrees) from the centre at which a key is pressed.
+    pub press_angle: f32,
+    /// Tilt (degrees) below which a pressed key is released. Kept below `press_angle` so keys do not chatter.
+    pub release_angle: f32,
+    /// Whether partial tilt pulses the key, holding it for a share of each period that grows with the tilt.
+    pub pulse: bool,
+    /// Tilt (degrees) at and beyond which a pulsed key is held continuously.
+    pub full_angle: f32,
+    /// Length (ms) of one press/release pulse period.
+    pub pulse_period_ms: u64,
+}
+
+impl Default for TiltKeysConfig {
+    fn default() -> Self {
+        TiltKeysConfig {
+            press_angle: 15.0,
+            release_angle: 10.0,
+            pulse: false,
+            full_angle: 35.0,
+            pulse_period_ms: 150,
+        }
+    }
+}
+
+/// What the controller appears as to the host
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum OutputMode {
+    /// Mouse and keyboard, moved according to `MouseMode`
+    #[default]
+    Mouse,
+    /// Virtual gamepad via uinput (Linux only)
+    Gamepad,
+}
+
+/// Virtual gamepad settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GamepadConfig {
+    /// Yaw (degrees) from centre that deflects the right stick fully sideways.
+    pub yaw_range: f32,
+    /// Pitch (degrees) from centre that deflects the right stick fully up or down.
+    pub pitch_range: f32,
+    /// Roll (degrees) from level that fully presses a trigger.
+    pub roll_range: f32,
+    /// Rotation (degrees) from centre that is ignored on every orientation axis.
+    pub deadzone: f32,
+    /// Whether rolling left/right presses the left/right trigger.
+    pub roll_to_triggers: bool,
+}
+
+impl Default for GamepadConfig {
+    fn default() -> Self {
+        GamepadConfig {
+            yaw_range: 30.0,
+            pitch_range: 30.0,
+            roll_range: 45.0,
+            deadzone: 2.0,
+            roll_to_triggers: true,
+        }
+    }
+}
+
+/// How input is injected into the host
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum InputBackendKind {
+    /// Synthetic events through enigo (X11, Windows, macOS)
+    #[default]
+    Enigo,
+    /// Kernel-level virtual devices through /dev/uinput (Linux only, works on Wayland)
+    Uinput,
+}
+
+/// Result of the point-at-corners screen calibration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScreenCalibration {
+    /// Homography from the controller's pointing direction to normalized screen coordinates
+    pub homography: Matrix3<f64>,
+}
+
+/// Pointer acceleration curve mapping input speed to a gain multiplier.
+/// Speeds are in touchpad widths per second for the touchpad and degrees per second for the gyro mouse.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum AccelerationCurve {
+    /// Gain rises linearly with the speed above `threshold`
+    Linear { slope: f32, threshold: f32 },
+    /// Gain rises with the speed above `threshold` raised to `exponent`
+    Power {
+        scale: f32,
+        exponent: f32,
+        threshold: f32,
+    },
+    /// Gain eases from `min_gain` to `max_gain`, centred on `midpoint`
+    Sigmoid {
+        min_gain: f32,
+        max_gain: f32,
+        midpoint: f32,
+        steepness: f32,
+    },
+    /// Piecewise-linear table of `[speed, gain]` points, held constant beyond either end
+    Table { points: Vec<[f32; 2]> },
+}
+
+impl AccelerationCurve {
+    /// Returns the gain multiplier for an input speed
+    pub fn gain(&self, speed: f32) -> f32 {
+        let speed = speed.max(0.0);
+        match self {
+            AccelerationCurve::Linear { slope, threshold } => {
+                1.0 + slope * (speed - threshold).max(0.0)
+            }
+            AccelerationCurve::Power {
+                scale,
+                exponent,
+                threshold,
+            } => 1.0 + scale * (speed - threshold).max(0.0).powf(*exponent),
+            AccelerationCurve::Sigmoid {
+                min_gain,
+                max_gain,
+                midpoint,
+                steepness,
+            } => min_gain + (max_gain - min_gain) / (1.0 + (-steepness * (speed - midpoint)).exp()),
+            AccelerationCurve::Table { points } => {
+                let mut points = points.clone();
+                points.sort_by(|a, b| a[0].total_cmp(&b[0]));
+                let (Some(first), Some(last)) = (points.first(), points.last()) else {
+                    return 1.0;
+                };
+                if speed <= first[0] {
+                    return first[1];
+                }
+                if speed >= last[0] {
+                    return last[1];
+                }
+                points
+                    .windows(2)
+                    .find(|pair| speed <= pair[1][0])
+                    .map_or(last[1], |pair| {
+                        let ([x0, y0], [x1, y1]) = (pair[0], pair[1]);
+                        let span = (x1 - x0).max(f32::EPSILON);
+                        y0 + (y1 - y0) * (speed - x0) / span
+                    })
+            }
+        }
+    }
+
+    /// Samples the curve at `count` evenly spaced speeds from 0 to `max_speed`, as `[speed, gain]` pairs
+    pub fn samples(&self, max_speed: f32, count: usize) -> Vec<[f32; 2]> {
+        let count = count.max(2);
+        (0..count)
+            .map(|i| {
+                let speed = max_speed * i as f32 / (count - 1) as f32;
+                [speed, self.gain(speed)]
+            })
+            .collect()
+    }
+}
+
+/// Area that absolute positioning is confined to
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum MappingRegion {
+    /// A fixed screen rectangle, in mouse coordinates
+    Rect {
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    },
+    /// The bounds of the first visible window whose title contains `title`, followed as it moves.
+    /// While no such window is found, the selected displays are used.
+    Window { title: String },
 }
 
 /// Mouse settings configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct MouseConfig {
+    /// Whether the controller drives the mouse and keyboard or a virtual gamepad
+    pub output_mode: OutputMode,
+    /// Virtual gamepad settings, used in gamepad output mode
+    pub gamepad: GamepadConfig,
     /// Mouse movement mode
     pub mode: MouseMode,
+    /// Modes the mode-cycle gesture steps through, in order
+    pub enabled_modes: Vec<MouseMode>,
     /// Mouse sensitivity for touchpad mode
     pub touchpad_sensitivity: f32,
     /// Acceleration factor for touchpad mode. 0.0 means no acceleration.
     pub touchpad_acceleration: f32,
+    /// Acceleration curve for the touchpad. `None` uses `touchpad_acceleration` and its threshold.
+    pub touchpad_curve: Option<AccelerationCurve>,
+    /// Maps the touchpad directly onto the mapping area like a graphics tablet, instead of moving relatively.
+    pub touchpad_absolute: bool,
     /// The speed threshold to activate acceleration. Below this, movement is linear (precise).
     /// The unit is abstract, related to (distance_squared / time_delta).
     pub touchpad_acceleration_threshold: f32,
@@ -32,17 +447,101 @@ pub struct MouseConfig {
     pub air_mouse_fov: f32,
     /// Rotational speed threshold (e.g., in degrees per second) to activate air mouse mode.
     pub air_mouse_activation_threshold: f32,
+    /// Indices of the displays the absolute air mouse spans. Empty means all displays.
+    pub air_mouse_displays: Vec<usize>,
+    /// Screen calibration used by the absolute air mouse instead of the FOV model.
+    /// It is relative to the orientation zeroed with Home, so recalibrate after re-centring elsewhere.
+    pub screen_calibration: Option<ScreenCalibration>,
+    /// Region the pointer is confined to and absolute positioning is scaled into.
+    /// `None` uses the selected displays.
+    pub mapping_region: Option<MappingRegion>,
+    /// Tilt-to-keys settings, used in tilt keys mode
+    pub tilt_keys: TiltKeysConfig,
+    /// Scrolling and zooming by tilting the controller while a button is held, in any mode
+    pub tilt_scroll: TiltScrollConfig,
+    /// Touchpad handwriting settings, used in handwriting mode
+    pub handwriting: HandwritingConfig,
+    /// Switch-access scanning settings, used in scanning mode
+    pub scanning: ScanningConfig,
+    /// Gyro aiming settings, used in gyro aim mode
+    pub gyro_aim: GyroAimConfig,
+    /// Gyro mouse gain at slow speeds, in pixels per degree of rotation.
+    pub gyro_mouse_sensitivity: f32,
+    /// Rotational speed (degrees per second) below which gyro mouse output is suppressed.
+    pub gyro_mouse_deadzone: f32,
+    /// Rotational speed (degrees per second) up to which the base sensitivity is used.
+    pub gyro_mouse_slow_speed: f32,
+    /// Rotational speed (degrees per second) at which the full fast multiplier applies.
+    pub gyro_mouse_fast_speed: f32,
+    /// Multiplier applied to the gyro mouse sensitivity at and above the fast speed.
+    pub gyro_mouse_fast_multiplier: f32,
+    /// Acceleration curve for the gyro mouse. `None` uses the slow/fast speed ramp.
+    pub gyro_mouse_curve: Option<AccelerationCurve>,
+    /// How long (ms) the pointer is held in place after a button press or release.
+    /// 0, the default, disables click stabilisation; around 150 ms suits users whose hand moves as they click.
+    pub click_stabilization_ms: u64,
+    /// Distance (pixels) the pointer must be pushed from the held position to break out of click stabilisation early.
+    pub click_stabilization_threshold: f32,
+    /// Whether resting the pointer performs a click (dwell clicking).
+    pub dwell_click_enabled: bool,
+    /// Distance (pixels) the pointer may wander while still counting as resting.
+    pub dwell_click_radius: f32,
+    /// How long (ms) the pointer must rest before the dwell click fires.
+    pub dwell_click_time_ms: u64,
+    /// Whether pointer output is smoothed by the speed-adaptive 1€ filter instead of a fixed lerp.
+    /// Off by default, so existing setups keep the pointer feel they are used to.
+    pub adaptive_smoothing: bool,
+    /// 1€ filter cutoff frequency (Hz) at rest. Lower values remove more jitter but add lag when moving slowly.
+    pub smoothing_min_cutoff: f32,
+    /// 1€ filter speed coefficient. Higher values reduce lag during fast movements.
+    pub smoothing_beta: f32,
+    /// How input is injected. Falls back to enigo if the selected backend is unavailable.
+    pub input_backend: InputBackendKind,
+    /// Number of wheel steps sent by a single `Scroll up/down/left/right` binding.
+    pub scroll_amount: i32,
+    /// Whether mode switches made from the controller are saved to the config file.
+    pub persist_mode_changes: bool,
 }
 
 impl Default for MouseConfig {
     fn default() -> Self {
         MouseConfig {
+            output_mode: OutputMode::Mouse,
+            gamepad: GamepadConfig::default(),
             mode: MouseMode::Touchpad,
+            enabled_modes: vec![MouseMode::AirMouse, MouseMode::Touchpad],
             touchpad_sensitivity: 500.0,
             touchpad_acceleration: 1.2,
+            touchpad_curve: None,
+            touchpad_absolute: false,
             touchpad_acceleration_threshold: 0.0002,
             air_mouse_fov: 40.0,
             air_mouse_activation_threshold: 5.0,
+            air_mouse_displays: Vec::new(),
+            screen_calibration: None,
+            mapping_region: None,
+            tilt_keys: TiltKeysConfig::default(),
+            tilt_scroll: TiltScrollConfig::default(),
+            gyro_aim: GyroAimConfig::default(),
+            scanning: ScanningConfig::default(),
+            handwriting: HandwritingConfig::default(),
+            gyro_mouse_sensitivity: 15.0,
+            gyro_mouse_deadzone: 1.5,
+            gyro_mouse_slow_speed: 10.0,
+            gyro_mouse_fast_speed: 120.0,
+            gyro_mouse_fast_multiplier: 2.5,
+            gyro_mouse_curve: None,
+            click_stabilization_ms: 0,
+            click_stabilization_threshold: 30.0,
+            dwell_click_enabled: false,
+            dwell_click_radius: 15.0,
+            dwell_click_time_ms: 800,
+            adaptive_smoothing: false,
+            smoothing_min_cutoff: 2.0,
+            smoothing_beta: 0.01,
+            input_backend: InputBackendKind::Enigo,
+            scroll_amount: 1,
+            persist_mode_changes: true,
         }
     }
 }
@@ -51,14 +51,19 @@ impl NotificationHandler {
 
         // Start task to process notifications
         let handle = tokio::spawn(async move {
-            Self::process_notifications(
+            let result = Self::process_notifications(
                 window,
                 notify_char,
                 controller_parser,
-                mouse_sender,
+                mouse_sender.clone(),
                 cancel_token,
             )
-            .await
+            .await;
+
+            // However the stream ended (cancelled, errored or closed), the mapper will
+            // never see the button releases, so release everything it is holding.
+            mouse_sender.release_all().await;
+            result
         });
         self.task_handle = Some(handle);
 
@@ -16,6 +16,7 @@ use tokio::sync::Mutex;
 use tokio::sync::mpsc;
 
 use crate::config::controller_config::ControllerConfig;
+use crate::core::tremor_filter::TremorFilter;
 
 /// Represents the state of the GearVR controller
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -57,6 +58,48 @@ pub struct ButtonState {
     pub no_button: bool,
 }
 
+impl ButtonState {
+    /// Returns whether the given button is currently pressed
+    pub fn is_pressed(&self, button: ControllerButton) -> bool {
+        match button {
+            ControllerButton::Trigger => self.trigger,
+            ControllerButton::Home => self.home,
+            ControllerButton::Back => self.back,
+            ControllerButton::VolumeUp => self.volume_up,
+            ControllerButton::VolumeDown => self.volume_down,
+            ControllerButton::Touchpad => self.touchpad,
+        }
+    }
+}
+
+/// Identifies a physical button on the controller
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ControllerButton {
+    Trigger,
+    Home,
+    Back,
+    VolumeUp,
+    VolumeDown,
+    Touchpad,
+}
+
+impl ControllerButton {
+    /// All buttons, in the order their bindings are processed
+    pub const ALL: [ControllerButton; 6] = [
+        ControllerButton::Trigger,
+        ControllerButton::Home,
+        ControllerButton::Back,
+        ControllerButton::VolumeUp,
+        ControllerButton::VolumeDown,
+        ControllerButton::Touchpad,
+    ];
+
+    /// Returns a stable index for per-button lookup tables
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
 /// Represents the state of the touchpad
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TouchpadState {
@@ -101,6 +144,8 @@ pub struct ControllerParser {
     recorded_gyro_data: Arc<Mutex<Vec<Vector3<f64>>>>,
     /// Smoothed orientation for reducing jitter.
     smoothed_orientation: UnitQuaternion<f64>,
+    /// Tremor suppression applied to the angular velocity before the AHRS update.
+    tremor_filter: TremorFilter,
 }
 
 impl ControllerParser {
@@ -127,6 +172,7 @@ impl ControllerParser {
             recorded_mag_data: Arc::new(Mutex::new(Vec::new())),
             recorded_gyro_data: Arc::new(Mutex::new(Vec::new())),
             smoothed_orientation: UnitQuaternion::identity(),
+            tremor_filter: TremorFilter::new(),
         }
     }
 
@@ -136,6 +182,11 @@ impl ControllerParser {
         let sample_period = self.ahrs_filter.sample_period(); // Keep the last known sample period
         self.ahrs_filter = Madgwick::<f64>::new(sample_period, new_config.madgwick_beta);
 
+        // Filter history from the previous settings would distort the first samples.
+        if new_config.tremor_suppression != self.config.tremor_suppression {
+            self.tremor_filter.reset();
+        }
+
         // Update the config struct itself
         self.config = new_config;
 
@@ -454,6 +505,13 @@ impl ControllerParser {
         let sample_period_ref: &mut f64 = self.ahrs_filter.sample_period_mut();
         *sample_period_ref = self.smoothed_delta_t;
 
+        // 震颤抑制：在 AHRS 更新前过滤角速度
+        let current_gyro_filtered = self.tremor_filter.apply(
+            &self.config.tremor_suppression,
+            current_gyro_filtered,
+            self.smoothed_delta_t,
+        );
+
         // 检查磁力计数据是否在有效范围内
         let mag_norm_max_threshold = self.config.local_earth_mag_field * 1.2; // 20% margin
         let mag_norm_min_threshold = self.config.local_earth_mag_field * 0.8; // 20% margin
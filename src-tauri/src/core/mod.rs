@@ -3,6 +3,7 @@
 
 pub mod bluetooth;
 pub mod controller;
+pub mod tremor_filter;
 
 // Re-export commonly used types
 pub use bluetooth::BluetoothManager;
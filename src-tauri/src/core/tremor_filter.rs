@@ -0,0 +1,220 @@
+//! Tremor suppression for angular velocity
+//! Physiological hand tremor sits roughly in the 4–12 Hz band. This module attenuates it in the
+//! gyroscope signal before orientation is estimated, so the pointer stays steady without the lag
+//! of heavier orientation smoothing.
+
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::f64::consts::{LN_2, PI};
+
+/// Lowest band edge (Hz) the band-stop accepts.
+const MIN_BAND_HZ: f64 = 0.1;
+
+/// Tremor suppression method
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(tag = "type")]
+pub enum TremorSuppression {
+    /// No tremor suppression
+    #[default]
+    Off,
+    /// Band-stop filter between `low_hz` and `high_hz`.
+    /// `strength` blends from the raw signal (0.0) to the fully filtered one (1.0).
+    BandStop {
+        low_hz: f64,
+        high_hz: f64,
+        strength: f64,
+    },
+    /// Linearly weighted moving average over the last `window` samples.
+    /// Steadier than the band-stop but adds latency as the window grows.
+    MovingAverage { window: usize },
+}
+
+/// Runtime state of the tremor filter, one per controller parser
+#[derive(Debug, Clone, Default)]
+pub struct TremorFilter {
+    /// Previous two inputs of the band-stop biquad, most recent first.
+    inputs: [Vector3<f64>; 2],
+    /// Previous two outputs of the band-stop biquad, most recent first.
+    outputs: [Vector3<f64>; 2],
+    /// Recent samples for the moving average, oldest first.
+    history: VecDeque<Vector3<f64>>,
+}
+
+impl TremorFilter {
+    /// Creates a filter with empty history
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Clears the filter history, e.g. after the settings change
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Filters one angular velocity sample taken `delta_t` seconds after the previous one
+    pub fn apply(
+        &mut self,
+        settings: &TremorSuppression,
+        angular_velocity: Vector3<f64>,
+        delta_t: f64,
+    ) -> Vector3<f64> {
+        match settings {
+            TremorSuppression::Off => angular_velocity,
+            TremorSuppression::BandStop {
+                low_hz,
+                high_hz,
+                strength,
+            } => {
+                let filtered = self.band_stop(angular_velocity, *low_hz, *high_hz, delta_t);
+                let strength = strength.clamp(0.0, 1.0);
+                angular_velocity + (filtered - angular_velocity) * strength
+            }
+            TremorSuppression::MovingAverage { window } => {
+                self.moving_average(angular_velocity, (*window).max(1))
+            }
+        }
+    }
+
+    /// Second-order band-stop (RBJ notch) centred on the geometric mean of the band edges.
+    /// Coefficients follow the actual sample rate, which drifts with the BLE connection.
+    /// After a long gap, e.g. a reconnect, the rate is too low to place the band; the history is
+    /// stale by then anyway, so it is cleared and the sample passes through.
+    fn band_stop(
+        &mut self,
+        input: Vector3<f64>,
+        low_hz: f64,
+        high_hz: f64,
+        delta_t: f64,
+    ) -> Vector3<f64> {
+        let sample_rate = 1.0 / delta_t.max(f64::EPSILON);
+        let nyquist = sample_rate / 2.0;
+        if nyquist * 0.95 <= MIN_BAND_HZ {
+            self.inputs = [Vector3::zeros(); 2];
+            self.outputs = [Vector3::zeros(); 2];
+            return input;
+        }
+        let low_hz = low_hz.clamp(MIN_BAND_HZ, nyquist * 0.95);
+        let high_hz = high_hz.clamp(low_hz * 1.01, nyquist * 0.99);
+
+        let center_hz = (low_hz * high_hz).sqrt();
+        let bandwidth_octaves = (high_hz / low_hz).log2();
+        let w0 = 2.0 * PI * center_hz / sample_rate;
+        let alpha = w0.sin() * (LN_2 / 2.0 * bandwidth_octaves * w0 / w0.sin()).sinh();
+        let cos_w0 = w0.cos();
+
+        let a0 = 1.0 + alpha;
+        let b0 = 1.0 / a0;
+        let b1 = -2.0 * cos_w0 / a0;
+        let b2 = 1.0 / a0;
+        let a1 = -2.0 * cos_w0 / a0;
+        let a2 = (1.0 - alpha) / a0;
+
+        let output = input * b0 + self.inputs[0] * b1 + self.inputs[1] * b2
+            - self.outputs[0] * a1
+            - self.outputs[1] * a2;
+
+        self.inputs = [input, self.inputs[0]];
+        self.outputs = [output, self.outputs[0]];
+        output
+    }
+
+    /// Moving average where the newest sample weighs the most.
+    fn moving_average(&mut self, input: Vector3<f64>, window: usize) -> Vector3<f64> {
+        self.history.push_back(input);
+        while self.history.len() > window {
+            self.history.pop_front();
+        }
+
+        let mut sum = Vector3::zeros();
+        let mut total_weight = 0.0;
+        for (i, sample) in self.history.iter().enumerate() {
+            let weight = (i + 1) as f64;
+            sum += sample * weight;
+            total_weight += weight;
+        }
+        sum / total_weight
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DELTA_T: f64 = 0.01;
+
+    fn tremor_band() -> TremorSuppression {
+        TremorSuppression::BandStop {
+            low_hz: 4.0,
+            high_hz: 12.0,
+            strength: 1.0,
+        }
+    }
+
+    /// Filters a unit sine on every axis for four seconds and returns the peak output over the
+    /// last second, once the filter has settled.
+    fn settled_amplitude(settings: &TremorSuppression, frequency_hz: f64) -> f64 {
+        let mut filter = TremorFilter::new();
+        let mut peak: f64 = 0.0;
+        for i in 0..400 {
+            let t = i as f64 * DELTA_T;
+            let value = (2.0 * PI * frequency_hz * t).sin();
+            let output = filter.apply(settings, Vector3::repeat(value), DELTA_T);
+            if i >= 300 {
+                peak = peak.max(output.amax());
+            }
+        }
+        peak
+    }
+
+    #[test]
+    fn band_stop_attenuates_a_tremor_at_the_notch() {
+        // The notch sits at the geometric mean of the band edges, about 6.9 Hz.
+        let amplitude = settled_amplitude(&tremor_band(), (4.0f64 * 12.0).sqrt());
+        assert!(amplitude < 0.05, "tremor amplitude {amplitude}");
+    }
+
+    #[test]
+    fn band_stop_passes_slow_motion() {
+        let amplitude = settled_amplitude(&tremor_band(), 0.5);
+        assert!(amplitude > 0.9, "slow motion amplitude {amplitude}");
+    }
+
+    #[test]
+    fn strength_blends_towards_the_raw_signal() {
+        let settings = TremorSuppression::BandStop {
+            low_hz: 4.0,
+            high_hz: 12.0,
+            strength: 0.5,
+        };
+        let amplitude = settled_amplitude(&settings, (4.0f64 * 12.0).sqrt());
+        assert!(
+            (0.45..=0.55).contains(&amplitude),
+            "half-strength amplitude {amplitude}"
+        );
+    }
+
+    #[test]
+    fn moving_average_passes_a_steady_rate() {
+        let settings = TremorSuppression::MovingAverage { window: 8 };
+        let mut filter = TremorFilter::new();
+        let rate = Vector3::new(10.0, -5.0, 2.0);
+        for _ in 0..20 {
+            assert_eq!(filter.apply(&settings, rate, DELTA_T), rate);
+        }
+    }
+
+    #[test]
+    fn band_stop_passes_through_after_a_long_gap() {
+        let settings = TremorSuppression::BandStop {
+            low_hz: 4.0,
+            high_hz: 12.0,
+            strength: 1.0,
+        };
+        let mut filter = TremorFilter::new();
+        let sample = Vector3::new(1.0, 2.0, 3.0);
+        filter.apply(&settings, sample, 0.01);
+
+        assert_eq!(filter.apply(&settings, sample, 10.0), sample);
+    }
+}
@@ -7,6 +7,7 @@ pub mod config;
 pub mod core;
 pub mod logging;
 pub mod mapping;
+pub mod overlay;
 pub mod state;
 pub mod tray;
 pub mod utils;
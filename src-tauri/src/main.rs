@@ -1,13 +1,19 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use gearvr_controller_bridge_lib::{logging, state::AppState, tray};
-use log::info;
-use tauri::{Manager, WindowEvent};
+use gearvr_controller_bridge_lib::{
+    logging, mapping::input_backend::EnigoBackend, overlay, state::AppState, tray,
+};
+use log::{info, warn};
+use std::time::Duration;
+use tauri::{Manager, RunEvent, WindowEvent};
 
 #[cfg(target_os = "macos")]
 use tauri::ActivationPolicy;
 
+/// How long quitting waits for the mapper thread to release held keys.
+const EXIT_RELEASE_TIMEOUT: Duration = Duration::from_millis(500);
+
 fn main() {
     tauri::Builder::default()
         .plugin(tauri_plugin_single_instance::init(|app, _args, _cwd| {
@@ -46,6 +52,10 @@ fn main() {
                     .init();
             }
 
+            if let Err(e) = overlay::create_overlay(app.handle()) {
+                warn!("Failed to create the overlay window: {}", e);
+            }
+
             Ok(())
         })
         .on_window_event(|window, event| match event {
@@ -60,6 +70,32 @@ fn main() {
             }
             _ => {}
         })
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app_handle, event| {
+            if let RunEvent::Exit = event {
+                // Make sure no injected key or mouse button stays pressed after we quit.
+                // Releases that need the main thread are handed back and run here, since this is
+                // the main thread and the event loop no longer runs jobs queued for it.
+                if let Some(app_state) = app_handle.try_state::<AppState>() {
+                    let mouse_sender = app_state.mouse_sender.clone();
+                    let released = tauri::async_runtime::block_on(async move {
+                        tokio::time::timeout(EXIT_RELEASE_TIMEOUT, async move {
+                            mouse_sender.lock().await.release_all_for_exit().await
+                        })
+                        .await
+                    });
+                    match released {
+                        Ok(jobs) => {
+                            if let Err(e) =
+                                EnigoBackend::run_jobs_on_current_thread(app_handle.clone(), jobs)
+                            {
+                                warn!("Failed to release keys on the main thread: {}", e);
+                            }
+                        }
+                        Err(_) => warn!("Timed out releasing held keys on exit"),
+                    }
+                }
+            }
+        });
 }
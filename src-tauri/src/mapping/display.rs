@@ -0,0 +1,130 @@
+//! Display layout helpers for multi-monitor pointer mapping
+//! This module describes the virtual desktop as a set of display rectangles in mouse coordinates.
+
+use tauri::AppHandle;
+
+/// A display's bounds in the coordinate space used for mouse movement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayRect {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl DisplayRect {
+    /// Creates a display rectangle from its top-left corner and size
+    pub fn new(x: i32, y: i32, width: i32, height: i32) -> Self {
+        Self {
+            x,
+            y,
+            width: width.max(1),
+            height: height.max(1),
+        }
+    }
+
+    /// The last pixel column inside the display
+    pub fn right(&self) -> i32 {
+        self.x + self.width - 1
+    }
+
+    /// The last pixel row inside the display
+    pub fn bottom(&self) -> i32 {
+        self.y + self.height - 1
+    }
+
+    /// Returns whether the point lies inside the display
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        x >= self.x && x <= self.right() && y >= self.y && y <= self.bottom()
+    }
+
+    /// Returns the point inside the display closest to the given point
+    pub fn clamp(&self, x: i32, y: i32) -> (i32, i32) {
+        (
+            x.clamp(self.x, self.right()),
+            y.clamp(self.y, self.bottom()),
+        )
+    }
+
+    /// Returns the centre of the display
+    pub fn center(&self) -> (i32, i32) {
+        (self.x + self.width / 2, self.y + self.height / 2)
+    }
+
+    /// Returns the smallest rectangle containing all of the given displays
+    pub fn bounding(displays: &[DisplayRect]) -> Option<DisplayRect> {
+        let first = displays.first()?;
+        let (mut left, mut top) = (first.x, first.y);
+        let (mut right, mut bottom) = (first.right(), first.bottom());
+        for display in &displays[1..] {
+            left = left.min(display.x);
+            top = top.min(display.y);
+            right = right.max(display.right());
+            bottom = bottom.max(display.bottom());
+        }
+        Some(DisplayRect::new(
+            left,
+            top,
+            right - left + 1,
+            bottom - top + 1,
+        ))
+    }
+}
+
+/// Queries the current monitor layout.
+/// Returns an empty list if the monitors cannot be enumerated.
+pub fn query_displays(app_handle: &AppHandle) -> Vec<DisplayRect> {
+    let monitors = match app_handle.available_monitors() {
+        Ok(monitors) => monitors,
+        Err(e) => {
+            eprintln!("Failed to enumerate monitors: {:?}", e);
+            return Vec::new();
+        }
+    };
+
+    monitors
+        .iter()
+        .map(|monitor| {
+            let position = monitor.position();
+            let size = monitor.size();
+            // Tauri reports physical pixels; on macOS the mouse is positioned in logical points.
+            let scale = if cfg!(target_os = "macos") {
+                monitor.scale_factor()
+            } else {
+                1.0
+            };
+            DisplayRect::new(
+                (position.x as f64 / scale).round() as i32,
+                (position.y as f64 / scale).round() as i32,
+                (size.width as f64 / scale).round() as i32,
+                (size.height as f64 / scale).round() as i32,
+            )
+        })
+        .collect()
+}
+
+/// Returns the index of the display containing the point, or of the nearest display
+pub fn display_index_at(displays: &[DisplayRect], x: i32, y: i32) -> Option<usize> {
+    displays
+        .iter()
+        .position(|display| display.contains(x, y))
+        .or_else(|| {
+            displays
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, display)| {
+                    let (cx, cy) = display.clamp(x, y);
+                    ((cx - x) as i64).pow(2) + ((cy - y) as i64).pow(2)
+                })
+                .map(|(index, _)| index)
+        })
+}
+
+/// Clamps a point to the nearest point inside any of the displays,
+/// so the pointer never lands in the gaps of a non-rectangular desktop
+pub fn clamp_to_displays(displays: &[DisplayRect], x: i32, y: i32) -> (i32, i32) {
+    match display_index_at(displays, x, y) {
+        Some(index) => displays[index].clamp(x, y),
+        None => (x, y),
+    }
+}
@@ -0,0 +1,148 @@
+//! Input injection backends
+//! `MouseMapper` drives the host through the `InputBackend` trait, so the injection mechanism can be
+//! swapped out. Enigo is the default; tests use `RecordingBackend` to record what would have been injected.
+
+use anyhow::Result;
+use enigo::{Axis, Button, Coordinate, Direction, Enigo, Key, Keyboard, Mouse, Settings};
+use tauri::AppHandle;
+
+use crate::config::mouse_config::InputBackendKind;
+use crate::mapping::display::{self, DisplayRect};
+#[cfg(target_os = "linux")]
+use crate::mapping::uinput_backend::UinputBackend;
+
+/// Work that must run on the app's main thread, e.g. typing Unicode characters on macOS.
+/// It receives a backend that is valid on that thread.
+pub type MainThreadJob = Box<dyn FnOnce(&mut dyn InputBackend) -> Result<()> + Send>;
+
+/// Creates the backend for an input backend kind; used when the configured kind changes.
+pub type BackendFactory = Box<dyn Fn(InputBackendKind) -> Box<dyn InputBackend> + Send>;
+
+/// A way of injecting mouse and keyboard input into the host
+pub trait InputBackend {
+    /// Moves the pointer to an absolute position or by a relative offset
+    fn move_mouse(&mut self, x: i32, y: i32, coordinate: Coordinate) -> Result<()>;
+
+    /// Presses, releases or clicks a mouse button
+    fn button(&mut self, button: Button, direction: Direction) -> Result<()>;
+
+    /// Presses, releases or clicks a key
+    fn key(&mut self, key: Key, direction: Direction) -> Result<()>;
+
+    /// Scrolls by a number of wheel steps; positive lengths scroll down/right
+    fn scroll(&mut self, length: i32, axis: Axis) -> Result<()>;
+
+    /// Returns the current pointer position
+    fn location(&self) -> Result<(i32, i32)>;
+
+    /// Returns the layout of all displays making up the virtual desktop
+    fn displays(&mut self) -> Result<Vec<DisplayRect>>;
+
+    /// Returns whether a key can only be injected from the main thread
+    fn needs_main_thread(&self, _key: &Key) -> bool {
+        false
+    }
+
+    /// Runs a job on the main thread. Backends without thread affinity run it immediately.
+    fn run_on_main_thread(&mut self, job: MainThreadJob) -> Result<()>;
+}
+
+/// Injects input through enigo
+pub struct EnigoBackend {
+    enigo: Enigo,
+    app_handle: AppHandle,
+}
+
+impl EnigoBackend {
+    /// Creates an enigo backend for the current thread
+    pub fn new(app_handle: AppHandle) -> Result<Self> {
+        let enigo = Enigo::new(&Settings::default())?;
+        Ok(Self { enigo, app_handle })
+    }
+
+    /// Runs main-thread jobs right away. Must be called on the main thread, e.g. while the app
+    /// is exiting and jobs queued with `run_on_main_thread` would never run.
+    pub fn run_jobs_on_current_thread(
+        app_handle: AppHandle,
+        jobs: Vec<MainThreadJob>,
+    ) -> Result<()> {
+        if jobs.is_empty() {
+            return Ok(());
+        }
+        let mut backend = EnigoBackend::new(app_handle)?;
+        for job in jobs {
+            if let Err(e) = job(&mut backend) {
+                eprintln!("Failed to inject input on the main thread: {:?}", e);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl InputBackend for EnigoBackend {
+    fn move_mouse(&mut self, x: i32, y: i32, coordinate: Coordinate) -> Result<()> {
+        Ok(self.enigo.move_mouse(x, y, coordinate)?)
+    }
+
+    fn button(&mut self, button: Button, direction: Direction) -> Result<()> {
+        Ok(self.enigo.button(button, direction)?)
+    }
+
+    fn key(&mut self, key: Key, direction: Direction) -> Result<()> {
+        Ok(self.enigo.key(key, direction)?)
+    }
+
+    fn scroll(&mut self, length: i32, axis: Axis) -> Result<()> {
+        Ok(self.enigo.scroll(length, axis)?)
+    }
+
+    fn location(&self) -> Result<(i32, i32)> {
+        Ok(self.enigo.location()?)
+    }
+
+    /// Queries the monitors through Tauri, falling back to enigo's main display.
+    fn displays(&mut self) -> Result<Vec<DisplayRect>> {
+        let displays = display::query_displays(&self.app_handle);
+        if !displays.is_empty() {
+            return Ok(displays);
+        }
+        let (width, height) = self.enigo.main_display()?;
+        Ok(vec![DisplayRect::new(0, 0, width, height)])
+    }
+
+    /// Unicode characters are typed through OS text input APIs that must run on the main thread.
+    fn needs_main_thread(&self, key: &Key) -> bool {
+        matches!(key, Key::Unicode(_))
+    }
+
+    fn run_on_main_thread(&mut self, job: MainThreadJob) -> Result<()> {
+        let app_handle = self.app_handle.clone();
+        self.app_handle.run_on_main_thread(move || {
+            let result = EnigoBackend::new(app_handle).and_then(|mut backend| job(&mut backend));
+            if let Err(e) = result {
+                eprintln!("Failed to inject input on the main thread: {:?}", e);
+            }
+        })?;
+        Ok(())
+    }
+}
+
+/// Creates the configured backend, falling back to enigo if it cannot be set up
+pub fn create_backend(app_handle: &AppHandle, kind: InputBackendKind) -> Box<dyn InputBackend> {
+    match kind {
+        InputBackendKind::Enigo => {}
+        #[cfg(target_os = "linux")]
+        InputBackendKind::Uinput => match UinputBackend::new(app_handle.clone()) {
+            Ok(backend) => return Box::new(backend),
+            Err(e) => eprintln!(
+                "Failed to create uinput devices, falling back to enigo: {:?}",
+                e
+            ),
+        },
+        #[cfg(not(target_os = "linux"))]
+        InputBackendKind::Uinput => {
+            eprintln!("The uinput backend is only available on Linux, falling back to enigo")
+        }
+    }
+    Box::new(EnigoBackend::new(app_handle.clone()).unwrap())
+}
@@ -1,3 +1,17 @@
+pub mod display;
+pub mod input_backend;
 pub mod mouse;
 /// This module handles the mapping of controller inputs to host system actions.
 mod mouse_mapper;
+mod one_euro_filter;
+#[cfg(test)]
+mod recording_backend;
+mod screen_calibration;
+#[cfg(target_os = "linux")]
+mod uinput;
+#[cfg(target_os = "linux")]
+mod uinput_backend;
+pub mod unistroke;
+#[cfg(target_os = "linux")]
+mod virtual_gamepad;
+mod window_bounds;
@@ -1,18 +1,28 @@
 use anyhow::Result;
-use log::{info, warn};
+use log::{error, info, warn};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread;
 use std::time::{Duration, Instant};
-use tauri::AppHandle;
-use tokio::sync::mpsc;
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::sync::{mpsc, oneshot};
 
 use crate::config::keymap_config::KeymapConfig;
 use crate::config::mouse_config::MouseConfig;
 use crate::core::controller::ControllerState;
-use crate::mapping::mouse_mapper::MouseMapper;
+use crate::mapping::input_backend::MainThreadJob;
+use crate::mapping::mouse_mapper::{MouseMapper, MouseMapperEvent};
+use crate::state::AppState;
 enum MouseMapperCommand {
     Update(ControllerState),
     UpdateMouseConfig(MouseConfig),
     UpdateKeymapConfig(KeymapConfig),
+    /// Release every held key and button, then acknowledge on the given channel.
+    ReleaseAll(oneshot::Sender<()>),
+    /// Release everything for app exit, replying with the releases that must run on the main thread.
+    ReleaseAllForExit(oneshot::Sender<Vec<MainThreadJob>>),
+    SetPaused(bool),
+    StartScreenCalibration,
 }
 
 /// A clonable handle that sends commands to the dedicated MouseMapper thread.
@@ -21,6 +31,7 @@ pub struct MouseMapperSender {
     pub mouse_config: MouseConfig,
     pub keymap_config: KeymapConfig,
     tx: mpsc::Sender<MouseMapperCommand>,
+    paused: Arc<AtomicBool>,
 }
 
 impl MouseMapperSender {
@@ -33,12 +44,19 @@ impl MouseMapperSender {
         let initial_mouse_config = mouse_config.clone();
         let initial_keymap_config = keymap_config.clone();
         let app_handle_clone = app_handle.clone();
+        let paused = Arc::new(AtomicBool::new(false));
+        let mapper_paused = paused.clone();
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+
+        tauri::async_runtime::spawn(Self::forward_mapper_events(app_handle.clone(), event_rx));
 
         thread::spawn(move || {
             let mut mouse_mapper = MouseMapper::new(
                 app_handle_clone,
                 initial_mouse_config,
                 initial_keymap_config,
+                mapper_paused,
+                event_tx,
             );
             info!("MouseMapper thread with interpolation started.");
 
@@ -46,6 +64,7 @@ impl MouseMapperSender {
             const INTERPOLATION_HZ: u64 = 250;
             let tick_duration = Duration::from_millis(1000 / INTERPOLATION_HZ);
             let mut last_update_time = Instant::now();
+            let mut data_timed_out = false;
 
             loop {
                 // 1. 非阻塞地检查是否有新的控制器数据
@@ -58,22 +77,49 @@ impl MouseMapperSender {
                         }
                         MouseMapperCommand::UpdateMouseConfig(new_mouse_config) => {
                             info!("Updating Mouse config");
-                            mouse_mapper.mouse_config = new_mouse_config;
+                            mouse_mapper.release_all();
+                            mouse_mapper.set_mouse_config(new_mouse_config);
                         }
                         MouseMapperCommand::UpdateKeymapConfig(new_keymap_config) => {
                             info!("Updating Keymap config");
+                            mouse_mapper.release_all();
                             mouse_mapper.keymap_config = new_keymap_config;
                         }
+                        MouseMapperCommand::ReleaseAll(done_tx) => {
+                            info!("Releasing all held keys");
+                            mouse_mapper.release_all();
+                            let _ = done_tx.send(());
+                        }
+                        MouseMapperCommand::ReleaseAllForExit(jobs_tx) => {
+                            info!("Releasing all held keys for exit");
+                            let _ = jobs_tx.send(mouse_mapper.release_all_for_exit());
+                        }
+                        MouseMapperCommand::SetPaused(paused) => {
+                            mouse_mapper.set_paused(paused);
+                        }
+                        MouseMapperCommand::StartScreenCalibration => {
+                            info!("Starting screen calibration");
+                            mouse_mapper.start_screen_calibration();
+                        }
                     }
                 }
 
-                // 2. 检查是否超过5秒没有数据更新
+                // 2. 推进连发、切换和 Turbo 按键的定时动作
+                mouse_mapper.binding_tick();
+
+                // 3. 检查是否超过5秒没有数据更新
                 if last_update_time.elapsed() < Duration::from_secs(5) {
                     // 只有最近5秒内有更新时才执行插值计算
+                    data_timed_out = false;
                     mouse_mapper.interpolate_tick();
+                } else if !data_timed_out {
+                    // 数据超时，释放所有仍被按下的按键
+                    info!("No controller data for 5 seconds, releasing held keys.");
+                    mouse_mapper.release_all();
+                    data_timed_out = true;
                 }
 
-                // 3. 等待一小段时间，以维持固定的循环频率
+                // 4. 等待一小段时间，以维持固定的循环频率
                 thread::sleep(tick_duration);
             }
         });
@@ -82,6 +128,94 @@ impl MouseMapperSender {
             mouse_config,
             keymap_config,
             tx,
+            paused,
+        }
+    }
+
+    /// Applies state changes reported by the mapper thread to the shared app state,
+    /// persists them if configured and forwards them to the frontend.
+    async fn forward_mapper_events(
+        app_handle: AppHandle,
+        mut event_rx: mpsc::UnboundedReceiver<MouseMapperEvent>,
+    ) {
+        while let Some(event) = event_rx.recv().await {
+            match event {
+                MouseMapperEvent::ModeChanged(mode) => {
+                    info!("Mouse mode switched from controller to {:?}", mode);
+
+                    if let Some(app_state) = app_handle.try_state::<AppState>() {
+                        let mut mouse_sender_guard = app_state.mouse_sender.lock().await;
+                        mouse_sender_guard.mouse_config.mode = mode;
+
+                        if mouse_sender_guard.mouse_config.persist_mode_changes {
+                            if let Err(e) = mouse_sender_guard
+                                .mouse_config
+                                .save_config(&app_handle)
+                                .await
+                            {
+                                error!("Failed to save mouse config after mode change: {}", e);
+                            }
+                        }
+                    }
+
+                    if let Err(e) = app_handle.emit("mouse-mode-changed", mode) {
+                        error!("Failed to emit mouse-mode-changed event: {}", e);
+                    }
+                }
+                MouseMapperEvent::ScreenCalibrated(calibration) => {
+                    info!("Screen calibration completed");
+
+                    if let Some(app_state) = app_handle.try_state::<AppState>() {
+                        let mut mouse_sender_guard = app_state.mouse_sender.lock().await;
+                        mouse_sender_guard.mouse_config.screen_calibration = Some(calibration);
+
+                        if let Err(e) = mouse_sender_guard
+                            .mouse_config
+                            .save_config(&app_handle)
+                            .await
+                        {
+                            error!(
+                                "Failed to save mouse config after screen calibration: {}",
+                                e
+                            );
+                        }
+                    }
+
+                    if let Err(e) = app_handle.emit(
+                        "screen-calibration-step",
+                        "settings.calibration.screen.steps.success",
+                    ) {
+                        error!("Failed to emit screen-calibration-step event: {}", e);
+                    }
+                    if let Err(e) = app_handle.emit("screen-calibration-finished", true) {
+                        error!("Failed to emit screen-calibration-finished event: {}", e);
+                    }
+                }
+                MouseMapperEvent::Frontend { event, payload } => {
+                    // `emit` runs backend listeners (e.g. the tray's "input-paused") right here on
+                    // this tokio task, so they must spawn instead of blocking on the runtime.
+                    if let Err(e) = app_handle.emit(event, payload) {
+                        error!("Failed to emit {} event: {}", event, e);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns whether input injection is currently paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// Returns the shared paused flag, for readers that cannot lock the sender.
+    pub fn paused_flag(&self) -> Arc<AtomicBool> {
+        self.paused.clone()
+    }
+
+    /// Pauses or resumes input injection on the mapper thread.
+    pub async fn set_paused(&self, paused: bool) {
+        if let Err(e) = self.tx.send(MouseMapperCommand::SetPaused(paused)).await {
+            warn!("Failed to send pause command to mouse thread: {}", e);
         }
     }
 
@@ -90,6 +224,48 @@ impl MouseMapperSender {
         Ok(())
     }
 
+    /// Releases every key and button held by the mapper thread and waits until it is done.
+    pub async fn release_all(&self) {
+        let (done_tx, done_rx) = oneshot::channel();
+        if let Err(e) = self.tx.send(MouseMapperCommand::ReleaseAll(done_tx)).await {
+            warn!("Failed to send release command to mouse thread: {}", e);
+            return;
+        }
+        // The sender is dropped without a reply if the mapper thread has already exited.
+        let _ = done_rx.await;
+    }
+
+    /// Releases all held keys and buttons when the app quits.
+    /// Returns the releases that must run on the main thread; the caller runs them itself,
+    /// since jobs queued on the event loop are never run once it is exiting.
+    pub async fn release_all_for_exit(&self) -> Vec<MainThreadJob> {
+        let (jobs_tx, jobs_rx) = oneshot::channel();
+        if let Err(e) = self
+            .tx
+            .send(MouseMapperCommand::ReleaseAllForExit(jobs_tx))
+            .await
+        {
+            warn!("Failed to send release command to mouse thread: {}", e);
+            return Vec::new();
+        }
+        // The sender is dropped without a reply if the mapper thread has already exited.
+        jobs_rx.await.unwrap_or_default()
+    }
+
+    /// Starts the point-at-corners screen calibration wizard on the mapper thread.
+    pub async fn start_screen_calibration(&self) {
+        if let Err(e) = self
+            .tx
+            .send(MouseMapperCommand::StartScreenCalibration)
+            .await
+        {
+            warn!(
+                "Failed to send screen calibration command to mouse thread: {}",
+                e
+            );
+        }
+    }
+
     pub async fn update_mouse_config(&mut self, mouse_config: MouseConfig) {
         self.mouse_config = mouse_config.clone();
         if let Err(e) = self
@@ -1,25 +1,240 @@
 //! Mouse and keyboard mapping for GearVR controller
-//! This module maps controller inputs to mouse and keyboard actions using the enigo library.
+//! This module maps controller inputs to mouse and keyboard actions through an `InputBackend`.
 
 use anyhow::{Ok, Result};
 use enigo::{
-    Button, Coordinate, Direction,
+    Axis, Button, Coordinate, Direction,
     Direction::{Click, Press, Release},
-    Enigo, Key, Keyboard, Mouse, Settings,
+    Key,
 };
-use nalgebra::UnitQuaternion;
-use tauri::AppHandle; // Import AppHandle
+use nalgebra::{UnitQuaternion, Vector3};
+use serde::Serialize;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+use tokio::sync::mpsc;
+
+use crate::config::keymap_config::{
+    ButtonBehavior, ChordKey, KeymapConfig, ModeCycleGesture, TiltDirection,
+};
+use crate::config::mouse_config::{
+    GyroAimConfig, MappingRegion, MouseConfig, MouseMode, OutputMode, ScanAction, ScanDirection,
+    ScreenCalibration, TiltScrollInput, TwistAction,
+};
+use crate::core::controller::{ButtonState, ControllerButton, ControllerState, TouchpadState};
+use crate::mapping::display::{self, DisplayRect};
+use crate::mapping::input_backend::{self, BackendFactory, InputBackend, MainThreadJob};
+use crate::mapping::one_euro_filter::OneEuroFilter;
+use crate::mapping::screen_calibration::{self, ScreenCalibrationWizard};
+use crate::mapping::unistroke::{self, UnistrokeRecognizer};
+#[cfg(target_os = "linux")]
+use crate::mapping::virtual_gamepad::{GamepadReport, VirtualGamepad};
+use crate::mapping::window_bounds::WindowTracker;
+
+/// How often the monitor layout is re-queried, so hot-plugged displays are picked up.
+const DISPLAY_REFRESH_INTERVAL: Duration = Duration::from_secs(3);
+
+/// How long a pause chord button's binding is held back waiting for the rest of the chord.
+const PAUSE_CHORD_WINDOW: Duration = Duration::from_millis(200);
+
+/// Actions handled by the mapper itself rather than injected as keys.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MapperAction {
+    /// Move the pointer to the centre of the next display.
+    NextDisplay,
+    /// Switch the dwell click to the next kind of click.
+    CycleDwellClick,
+}
+
+impl MapperAction {
+    /// Parses a binding string into a mapper action.
+    fn from_binding(key_str: &str) -> Option<Self> {
+        match key_str.trim().to_lowercase().as_str() {
+            "next display" => Some(MapperAction::NextDisplay),
+            "dwell click" => Some(MapperAction::CycleDwellClick),
+            _ => None,
+        }
+    }
+}
+
+/// The click performed when the pointer dwells.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize)]
+pub enum DwellClickKind {
+    #[default]
+    Left,
+    Right,
+    Double,
+    /// Alternately presses and releases the left button.
+    Drag,
+}
+
+impl DwellClickKind {
+    /// Returns the kind selected after this one.
+    fn next(self) -> Self {
+        match self {
+            DwellClickKind::Left => DwellClickKind::Right,
+            DwellClickKind::Right => DwellClickKind::Double,
+            DwellClickKind::Double => DwellClickKind::Drag,
+            DwellClickKind::Drag => DwellClickKind::Left,
+        }
+    }
+}
+
+/// Progress of the current dwell, sent to the frontend as "dwell-progress".
+#[derive(Debug, Clone, Serialize)]
+struct DwellProgress {
+    x: i32,
+    y: i32,
+    /// Fraction of the dwell time elapsed, from 0.0 to 1.0.
+    progress: f32,
+    kind: DwellClickKind,
+}
 
-use crate::config::keymap_config::KeymapConfig;
-use crate::config::mouse_config::{MouseConfig, MouseMode};
-use crate::core::controller::{ButtonState, ControllerState, TouchpadState};
+/// The pointer position a dwell is being timed at.
+#[derive(Debug, Clone, Copy)]
+struct DwellState {
+    anchor: (i32, i32),
+    since: Instant,
+    /// Whether the click already fired; the pointer must move away to re-arm.
+    fired: bool,
+    /// Last progress step reported to the frontend.
+    reported_step: u32,
+}
+
+/// Number of progress events sent over one dwell.
+const DWELL_PROGRESS_STEPS: u32 = 20;
+
+/// State changes made by the mapper thread that the rest of the app needs to know about.
+#[derive(Debug, Clone)]
+pub enum MouseMapperEvent {
+    /// The mouse mode was switched from the controller.
+    ModeChanged(MouseMode),
+    /// The point-at-corners screen calibration completed.
+    ScreenCalibrated(ScreenCalibration),
+    /// An event for the frontend, forwarded as-is.
+    Frontend {
+        event: &'static str,
+        payload: serde_json::Value,
+    },
+}
+
+/// A press of the mode-cycle gesture button whose binding is being held back
+/// until it is clear whether the press is part of the gesture.
+#[derive(Debug, Clone, Copy)]
+struct PendingGesturePress {
+    /// When the button was pressed.
+    since: Instant,
+    /// Whether the button has already been released again.
+    released: bool,
+}
+
+/// Pointer position held in place around a button press, so the click does not drift.
+#[derive(Debug, Clone, Copy)]
+struct ClickFreeze {
+    /// The held pointer position.
+    anchor: (i32, i32),
+    /// Where the pointer would be without the freeze; movement keeps accumulating here.
+    shadow: (i32, i32),
+    /// When the freeze ends.
+    until: Instant,
+}
+
+/// A flick stick turn being spread over the flick time.
+#[derive(Debug, Clone, Copy)]
+struct Flick {
+    /// Total turn in camera degrees.
+    angle: f32,
+    /// Degrees of the turn already sent.
+    sent: f32,
+    started: Instant,
+}
+
+/// Wraps an angle difference into -180..180 degrees.
+fn wrap_degrees(angle: f32) -> f32 {
+    (angle + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// The open radial menu.
+#[derive(Debug, Clone, Copy)]
+struct RadialMenuState {
+    /// Orientation when the menu was opened; tilt is measured from here.
+    anchor: UnitQuaternion<f64>,
+    /// The highlighted slice, if the selection is past the deadzone.
+    highlighted: Option<usize>,
+}
+
+/// Press state of one tilt keys direction.
+#[derive(Debug, Clone, Default)]
+struct TiltKeyState {
+    /// Whether the tilt is past the press angle, with hysteresis.
+    engaged: bool,
+    /// The binding currently held down, kept so it is released even if the keymap changes.
+    held: Option<String>,
+}
+
+/// The scanning highlight, sent to the frontend as "scan-highlight", or `null` once scanning stops.
+#[derive(Debug, Clone, Serialize)]
+struct ScanHighlight {
+    /// Labels of the actions in the current layer.
+    actions: Vec<String>,
+    /// Index of the highlighted action.
+    index: usize,
+    /// Whether a move action is gliding the pointer; the highlight is paused meanwhile.
+    moving: bool,
+}
+
+/// Runtime state of switch-access scanning.
+#[derive(Debug, Clone)]
+struct ScanState {
+    /// Indices of the layers entered, from the top-level list down.
+    layers: Vec<usize>,
+    /// Index of the highlighted action in the current layer.
+    highlighted: usize,
+    /// When the highlight moves on.
+    next_step: Instant,
+    /// Direction the pointer is gliding in, if a move action was selected.
+    moving: Option<ScanDirection>,
+    /// When the last tick ran, for the glide distance.
+    last_tick: Instant,
+    /// Sub-pixel glide carried between ticks.
+    remainder: (f32, f32),
+}
+
+/// Recognition result of one handwriting stroke, reported to the frontend.
+#[derive(Debug, Clone, Serialize)]
+struct HandwritingResult {
+    /// The best matching template's output, if any templates exist.
+    output: Option<String>,
+    /// Match score from 0.0 to 1.0.
+    score: f32,
+    /// Whether the output was typed, i.e. the score reached the minimum.
+    typed: bool,
+}
+
+/// Runtime state of a single button binding, used by the repeat, toggle and turbo behaviours.
+#[derive(Debug, Clone, Copy, Default)]
+struct BindingRuntime {
+    /// Whether a toggle binding is currently latched down.
+    toggled: bool,
+    /// Whether a turbo binding is currently in the pressed half of its cycle.
+    turbo_down: bool,
+    /// When the next repeat or turbo step is due.
+    next_fire: Option<Instant>,
+}
+
+/// Source of the current time for timed bindings, gestures, dwell and click freezes.
+/// Tests replace it with a manual clock so timed behaviour runs without sleeping.
+type Clock = Box<dyn Fn() -> Instant + Send>;
 
 /// Maps controller inputs to mouse and keyboard actions
 pub struct MouseMapper {
-    /// Enigo instance for input simulation
-    enigo: Enigo,
-    /// The Tauri app handle to run commands on the main thread
-    app_handle: AppHandle,
+    /// Backend used to inject input into the host
+    backend: Box<dyn InputBackend>,
+    /// Current time source
+    clock: Clock,
+    /// Creates a new backend when the configured backend kind changes.
+    create_backend: BackendFactory,
     /// Current mouse configuration
     pub mouse_config: MouseConfig,
     /// Current keymap configuration
@@ -36,8 +251,10 @@ pub struct MouseMapper {
     is_precision_mode_active: bool,
     /// A flag to indicate if the air mouse movement is currently active.
     is_air_mouse_active: bool,
-    /// Timestamp of the last home button press, for double-click detection.
-    home_button_last_press_time: Option<u64>,
+    /// Mode-cycle gesture press whose binding has been deferred.
+    mode_gesture_pending: Option<PendingGesturePress>,
+    /// Whether the next release of the gesture button belongs to a completed gesture and must be ignored.
+    mode_gesture_swallow_release: bool,
     // --- Fields for seamless precision mode transition ---
     /// The controller's yaw when precision mode was activated.
     precision_mode_center_yaw: f32,
@@ -47,20 +264,113 @@ pub struct MouseMapper {
     precision_mode_start_x: i32,
     /// The mouse's screen Y coordinate when precision mode was activated.
     precision_mode_start_y: i32,
+    /// Per-button runtime state for repeat, toggle and turbo bindings.
+    binding_runtime: [BindingRuntime; ControllerButton::ALL.len()],
+    /// Key strings that have been pressed and not yet released, in press order.
+    held_keys: Vec<String>,
+    /// When set, main-thread jobs are collected here instead of being queued on the event loop.
+    collected_main_thread_jobs: Option<Vec<MainThreadJob>>,
+    /// Whether input injection is paused. Shared with `MouseMapperSender` so it can be read
+    /// from commands and the tray without a round-trip to the mapper thread.
+    paused: Arc<AtomicBool>,
+    /// Timestamp at which the pause chord started being held.
+    pause_chord_since: Option<u64>,
+    /// Whether the current pause chord hold has already toggled the paused state.
+    pause_chord_fired: bool,
+    /// Presses of pause chord buttons held back until it is clear whether the chord is being
+    /// performed, with the touchpad state at the time of the press. Indexed by `ControllerButton::index`.
+    pause_chord_pending: [Option<(Instant, TouchpadState)>; ControllerButton::ALL.len()],
+    /// Pause chord buttons whose release must be swallowed because the chord toggled pause.
+    pause_chord_swallow: [bool; ControllerButton::ALL.len()],
+    /// Back-channel for state changes made on the mapper thread.
+    event_tx: mpsc::UnboundedSender<MouseMapperEvent>,
+    /// Cached layout of all displays making up the virtual desktop.
+    displays: Vec<DisplayRect>,
+    /// When the display layout was last queried.
+    displays_refreshed_at: Option<Instant>,
+    /// The running point-at-corners screen calibration, if any.
+    screen_calibration_wizard: Option<ScreenCalibrationWizard>,
+    /// Adaptive filter applied to the pointer on its way to the target position.
+    pointer_filter: OneEuroFilter,
+    /// When `interpolate_tick` last moved the pointer.
+    last_interpolate_at: Option<Instant>,
+    /// Active click stabilisation, if a button changed state recently.
+    click_freeze: Option<ClickFreeze>,
+    /// The click the dwell mode performs next.
+    dwell_click_kind: DwellClickKind,
+    /// The dwell currently being timed.
+    dwell: Option<DwellState>,
+    /// Whether a dwell drag is holding the left button.
+    dwell_dragging: bool,
+    /// Roll and pitch (degrees) tilt keys are measured from, captured when the mode is entered.
+    tilt_keys_center: Option<(f32, f32)>,
+    /// When tilt keys mode was entered; pulse periods are counted from here.
+    tilt_keys_started: Instant,
+    /// Press state of each tilt direction, indexed by `TiltDirection::index`.
+    tilt_key_states: [TiltKeyState; TiltDirection::ALL.len()],
+    /// The flick stick turn in progress, if any.
+    flick: Option<Flick>,
+    /// Finger direction (degrees) on the flick stick last frame, `None` when it is not engaged.
+    flick_stick_angle: Option<f32>,
+    /// Touchpad points of the handwriting stroke being drawn.
+    handwriting_stroke: Vec<(f32, f32)>,
+    /// Switch-access scanning state, present while in scanning mode.
+    scanning: Option<ScanState>,
+    /// Keys pressed so far in the chord being typed.
+    chord: Vec<ChordKey>,
+    /// The radial menu, while its button is held.
+    radial_menu: Option<RadialMenuState>,
+    /// Orientation when the tilt scroll button was pressed, `None` while it is not held.
+    tilt_scroll_anchor: Option<UnitQuaternion<f64>>,
+    /// Fractional wheel steps carried between frames: (vertical, twist).
+    tilt_scroll_remainder: (f32, f32),
+    /// The virtual gamepad driven in gamepad output mode.
+    #[cfg(target_os = "linux")]
+    gamepad: Option<VirtualGamepad>,
+    /// Yaw the right stick is centred on, captured on the first gamepad frame.
+    /// The compass heading drifts and depends on where the user sits, so absolute yaw is never used.
+    #[cfg(target_os = "linux")]
+    gamepad_center_yaw: Option<f32>,
+    /// Follows the target window of a window mapping region.
+    window_tracker: Option<WindowTracker>,
 }
 
 impl MouseMapper {
-    /// Creates a new mouse mapper with default configuration
+    /// Creates a new mouse mapper that injects input through the configured backend
     pub fn new(
         app_handle: AppHandle,
         mouse_config: MouseConfig,
         keymap_config: KeymapConfig,
+        paused: Arc<AtomicBool>,
+        event_tx: mpsc::UnboundedSender<MouseMapperEvent>,
+    ) -> Self {
+        Self::with_backend(
+            mouse_config,
+            keymap_config,
+            paused,
+            event_tx,
+            Box::new(move |kind| input_backend::create_backend(&app_handle, kind)),
+        )
+    }
+
+    /// Creates a new mouse mapper that injects input through backends made by `create_backend`.
+    /// Needs no app handle: events for the frontend go out over `event_tx`.
+    pub fn with_backend(
+        mouse_config: MouseConfig,
+        keymap_config: KeymapConfig,
+        paused: Arc<AtomicBool>,
+        event_tx: mpsc::UnboundedSender<MouseMapperEvent>,
+        create_backend: BackendFactory,
     ) -> Self {
-        let enigo = Enigo::new(&Settings::default()).unwrap();
-        let (x, y) = enigo.location().unwrap();
-        Self {
-            enigo,
-            app_handle,
+        let backend = create_backend(mouse_config.input_backend);
+        let (x, y) = backend.location().unwrap_or_else(|e| {
+            eprintln!("Failed to read the pointer position: {:?}", e);
+            (0, 0)
+        });
+        let mut mapper = Self {
+            backend,
+            clock: Box::new(Instant::now),
+            create_backend,
             mouse_config,
             keymap_config,
             last_state: None,
@@ -70,49 +380,469 @@ impl MouseMapper {
             target_screen_y: y,
             is_precision_mode_active: false,
             is_air_mouse_active: false,
-            home_button_last_press_time: None,
+            mode_gesture_pending: None,
+            mode_gesture_swallow_release: false,
             precision_mode_center_yaw: 0.0,
             precision_mode_center_pitch: 0.0,
             precision_mode_start_x: 0,
             precision_mode_start_y: 0,
+            binding_runtime: Default::default(),
+            held_keys: Vec::new(),
+            collected_main_thread_jobs: None,
+            paused,
+            pause_chord_since: None,
+            pause_chord_fired: false,
+            pause_chord_pending: Default::default(),
+            pause_chord_swallow: [false; ControllerButton::ALL.len()],
+            event_tx,
+            displays: Vec::new(),
+            displays_refreshed_at: None,
+            screen_calibration_wizard: None,
+            pointer_filter: OneEuroFilter::new(),
+            last_interpolate_at: None,
+            click_freeze: None,
+            dwell_click_kind: DwellClickKind::default(),
+            dwell: None,
+            dwell_dragging: false,
+            tilt_keys_center: None,
+            tilt_keys_started: Instant::now(),
+            tilt_key_states: Default::default(),
+            flick: None,
+            flick_stick_angle: None,
+            tilt_scroll_anchor: None,
+            tilt_scroll_remainder: (0.0, 0.0),
+            radial_menu: None,
+            handwriting_stroke: Vec::new(),
+            chord: Vec::new(),
+            scanning: None,
+            #[cfg(target_os = "linux")]
+            gamepad: None,
+            #[cfg(target_os = "linux")]
+            gamepad_center_yaw: None,
+            window_tracker: None,
+        };
+        mapper.sync_gamepad_output();
+        mapper.sync_window_tracker();
+        mapper
+    }
+
+    /// Applies a new mouse config, switching the input backend if a different one is selected.
+    pub fn set_mouse_config(&mut self, mouse_config: MouseConfig) {
+        if mouse_config.input_backend != self.mouse_config.input_backend {
+            self.backend = (self.create_backend)(mouse_config.input_backend);
+            self.displays_refreshed_at = None;
+        }
+        if mouse_config.mode != self.mouse_config.mode {
+            self.release_tilt_keys();
+        }
+        let output_mode_changed = mouse_config.output_mode != self.mouse_config.output_mode;
+        self.mouse_config = mouse_config;
+        if output_mode_changed {
+            self.release_all();
+            self.sync_gamepad_output();
+        }
+        self.sync_window_tracker();
+    }
+
+    /// Starts or stops following a window to match the configured mapping region.
+    fn sync_window_tracker(&mut self) {
+        let title = match &self.mouse_config.mapping_region {
+            Some(MappingRegion::Window { title }) => title,
+            _ => {
+                self.window_tracker = None;
+                return;
+            }
+        };
+        if self
+            .window_tracker
+            .as_ref()
+            .is_none_or(|tracker| tracker.title() != title)
+        {
+            self.window_tracker = Some(WindowTracker::new(title));
+        }
+    }
+
+    /// Creates or removes the virtual gamepad to match the configured output mode.
+    #[cfg(target_os = "linux")]
+    fn sync_gamepad_output(&mut self) {
+        if self.mouse_config.output_mode != OutputMode::Gamepad {
+            self.gamepad = None;
+            return;
+        }
+        if self.gamepad.is_none() {
+            // Centre the right stick on wherever the controller points when gamepad output starts.
+            self.gamepad_center_yaw = None;
+            self.gamepad = VirtualGamepad::new()
+                .map_err(|e| {
+                    eprintln!(
+                        "Failed to create virtual gamepad, falling back to mouse output: {:?}",
+                        e
+                    )
+                })
+                .ok();
+        }
+    }
+
+    /// Creates or removes the virtual gamepad to match the configured output mode.
+    #[cfg(not(target_os = "linux"))]
+    fn sync_gamepad_output(&mut self) {
+        if self.mouse_config.output_mode == OutputMode::Gamepad {
+            eprintln!("Gamepad output is only available on Linux, falling back to mouse output");
+        }
+    }
+
+    /// Returns whether the controller currently drives a virtual gamepad.
+    #[cfg(target_os = "linux")]
+    fn is_gamepad_output(&self) -> bool {
+        self.gamepad.is_some()
+    }
+
+    /// Returns whether the controller currently drives a virtual gamepad.
+    #[cfg(not(target_os = "linux"))]
+    fn is_gamepad_output(&self) -> bool {
+        false
+    }
+
+    /// Sends the controller state to the virtual gamepad.
+    #[cfg(target_os = "linux")]
+    fn update_gamepad(&mut self, state: &ControllerState) {
+        let (roll, pitch, yaw) = Self::to_display_frame(&state.orientation).euler_angles();
+        let yaw = yaw.to_degrees() as f32;
+        let center_yaw = *self.gamepad_center_yaw.get_or_insert(yaw);
+        let angles = (
+            roll.to_degrees() as f32,
+            pitch.to_degrees() as f32,
+            wrap_degrees(yaw - center_yaw),
+        );
+        let report = GamepadReport::from_state(state, angles, &self.mouse_config.gamepad);
+        if let Some(gamepad) = self.gamepad.as_mut() {
+            if let Err(e) = gamepad.send(&report) {
+                eprintln!("Failed to send gamepad report: {:?}", e);
+            }
+        }
+    }
+
+    /// Sends the controller state to the virtual gamepad.
+    #[cfg(not(target_os = "linux"))]
+    fn update_gamepad(&mut self, _state: &ControllerState) {}
+
+    /// Returns whether input injection is currently paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// Pauses or resumes input injection and notifies the frontend.
+    /// Pausing releases all held keys so nothing stays pressed while paused.
+    pub fn set_paused(&mut self, paused: bool) {
+        if self.paused.swap(paused, Ordering::SeqCst) == paused {
+            return;
+        }
+
+        if paused {
+            self.release_all();
+            self.is_air_mouse_active = false;
+            self.is_precision_mode_active = false;
+        } else {
+            // Chord releases are not seen while paused, so a resume from elsewhere must not
+            // leave chord buttons waiting to swallow their next press.
+            self.reset_pause_chord_deferral();
+            // Resuming recentres the gamepad's right stick, e.g. after the user turned their seat.
+            #[cfg(target_os = "linux")]
+            {
+                self.gamepad_center_yaw = None;
+            }
+        }
+
+        eprintln!(
+            "Input injection {}.",
+            if paused { "paused" } else { "resumed" }
+        );
+        self.emit_event("input-paused", paused);
+    }
+
+    /// Starts the point-at-corners screen calibration wizard.
+    /// While it runs, Trigger samples the current corner, Back cancels,
+    /// and no other input is injected.
+    pub fn start_screen_calibration(&mut self) {
+        self.release_all();
+        self.is_air_mouse_active = false;
+        self.is_precision_mode_active = false;
+
+        let wizard = ScreenCalibrationWizard::new();
+        if let Some(step) = wizard.current_step() {
+            self.emit_event("screen-calibration-step", step);
+        }
+        self.screen_calibration_wizard = Some(wizard);
+    }
+
+    /// Advances the screen calibration wizard on Trigger and Back presses.
+    fn update_screen_calibration(&mut self, state: &ControllerState) {
+        let last_buttons = self
+            .last_state
+            .as_ref()
+            .map(|last| last.buttons.clone())
+            .unwrap_or_default();
+
+        if state.buttons.back && !last_buttons.back {
+            eprintln!("Screen calibration cancelled.");
+            self.screen_calibration_wizard = None;
+            self.emit_event("screen-calibration-finished", false);
+            return;
+        }
+        if !state.buttons.trigger || last_buttons.trigger {
+            return;
+        }
+
+        let Some(wizard) = self.screen_calibration_wizard.as_mut() else {
+            return;
+        };
+        let direction = Self::to_display_frame(&state.orientation) * Vector3::x();
+        wizard.add_sample(direction);
+
+        if let Some(step) = wizard.current_step() {
+            self.emit_event("screen-calibration-step", step);
+            return;
+        }
+
+        match wizard.fit() {
+            Some(calibration) => {
+                eprintln!("Screen calibration fitted: {:?}", calibration.homography);
+                self.mouse_config.screen_calibration = Some(calibration.clone());
+                // The sender saves the config and notifies the frontend once it is persisted.
+                if let Err(e) = self
+                    .event_tx
+                    .send(MouseMapperEvent::ScreenCalibrated(calibration))
+                {
+                    eprintln!("Failed to report screen calibration: {:?}", e);
+                }
+            }
+            None => {
+                eprintln!("Screen calibration failed: corner samples are degenerate.");
+                self.emit_event(
+                    "screen-calibration-step",
+                    "settings.calibration.screen.steps.failed",
+                );
+                self.emit_event("screen-calibration-finished", false);
+            }
+        }
+        self.screen_calibration_wizard = None;
+    }
+
+    /// Returns the current time from the mapper's clock.
+    fn now(&self) -> Instant {
+        (self.clock)()
+    }
+
+    /// Returns the pointer position, or `None` after logging if the backend cannot report it.
+    /// Callers skip their step for this frame rather than act on a made-up position.
+    fn pointer_location(&self) -> Option<(i32, i32)> {
+        self.backend
+            .location()
+            .map_err(|e| eprintln!("Failed to read the pointer position: {:?}", e))
+            .ok()
+    }
+
+    /// Sends an event to the frontend over the back-channel, logging any failure.
+    fn emit_event<S: serde::Serialize>(&self, event: &'static str, payload: S) {
+        let result = serde_json::to_value(payload)
+            .map_err(anyhow::Error::from)
+            .and_then(|payload| {
+                self.event_tx
+                    .send(MouseMapperEvent::Frontend { event, payload })
+                    .map_err(anyhow::Error::from)
+            });
+        if let Err(e) = result {
+            eprintln!("Failed to emit {} event: {:?}", event, e);
+        }
+    }
+
+    /// Switches the mouse mode and reports the change over the back-channel,
+    /// so the sender, the saved config and the frontend stay in sync.
+    fn set_mode(&mut self, mode: MouseMode) {
+        if self.mouse_config.mode == mode {
+            return;
+        }
+        self.mouse_config.mode = mode;
+        self.is_air_mouse_active = false;
+        self.is_precision_mode_active = false;
+        self.release_tilt_keys();
+        self.flick = None;
+        self.flick_stick_angle = None;
+        self.handwriting_stroke.clear();
+        self.chord.clear();
+        self.stop_scanning();
+
+        if let Err(e) = self.event_tx.send(MouseMapperEvent::ModeChanged(mode)) {
+            eprintln!("Failed to report mouse mode change: {:?}", e);
+        }
+    }
+
+    /// Toggles the paused state when the configured pause chord has been held long enough.
+    fn detect_pause_gesture(&mut self, state: &ControllerState) {
+        let chord = &self.keymap_config.pause_chord;
+        if chord.is_empty() || !chord.iter().all(|&b| state.buttons.is_pressed(b)) {
+            self.pause_chord_since = None;
+            self.pause_chord_fired = false;
+            return;
+        }
+
+        let now = state.timestamp;
+        let since = *self.pause_chord_since.get_or_insert(now);
+        if !self.pause_chord_fired && now.saturating_sub(since) >= self.keymap_config.pause_hold_ms
+        {
+            self.pause_chord_fired = true;
+            self.set_paused(!self.is_paused());
+            // The chord was a pause gesture: its buttons' bindings never fire.
+            for button in self.keymap_config.pause_chord.clone() {
+                self.pause_chord_pending[button.index()] = None;
+                self.pause_chord_swallow[button.index()] = true;
+            }
+        }
+    }
+
+    /// Handles an edge of a button that is part of the pause chord.
+    /// Its press is held back while the chord may be forming, so holding the chord does not
+    /// auto-repeat the buttons' bindings; a press that turns out not to be a pause is replayed.
+    fn handle_pause_chord_edge(
+        &mut self,
+        button: ControllerButton,
+        is_pressed: bool,
+        touchpad: &TouchpadState,
+    ) {
+        let index = button.index();
+        if self.pause_chord_swallow[index] {
+            if !is_pressed {
+                self.pause_chord_swallow[index] = false;
+            }
+            return;
+        }
+
+        if is_pressed {
+            self.pause_chord_pending[index] = Some((self.now(), touchpad.clone()));
+        } else if let Some((_, pressed_touchpad)) = self.pause_chord_pending[index].take() {
+            // Released before the pause fired: it was a plain click.
+            self.route_button_edge(button, true, &pressed_touchpad);
+            self.route_button_edge(button, false, touchpad);
+        } else {
+            self.route_button_edge(button, false, touchpad);
+        }
+    }
+
+    /// Drops held-back pause chord presses and pending swallowed releases.
+    fn reset_pause_chord_deferral(&mut self) {
+        self.pause_chord_pending = Default::default();
+        self.pause_chord_swallow = Default::default();
+    }
+
+    /// Replays held-back pause chord presses once the chord has not formed within its window.
+    fn flush_pause_chord(&mut self, now: Instant) {
+        // While the whole chord is held, the presses stay held back until it fires or breaks up.
+        if self.pause_chord_since.is_some() {
+            return;
+        }
+        for button in ControllerButton::ALL {
+            let index = button.index();
+            let is_due = self.pause_chord_pending[index]
+                .as_ref()
+                .is_some_and(|(since, _)| now.duration_since(*since) >= PAUSE_CHORD_WINDOW);
+            if is_due {
+                if let Some((_, touchpad)) = self.pause_chord_pending[index].take() {
+                    self.route_button_edge(button, true, &touchpad);
+                }
+            }
         }
     }
 
     /// Updates the mouse mapper with new controller state
     pub fn update(&mut self, state: &ControllerState) {
+        // --- Pause gesture is checked first so it can also resume a paused mapper ---
+        self.detect_pause_gesture(state);
+        if self.is_paused() {
+            // Keep tracking state so resuming does not produce spurious button edges.
+            self.last_state = Some(state.clone());
+            return;
+        }
+
+        // --- The screen calibration wizard takes over the controller while it runs ---
+        if self.screen_calibration_wizard.is_some() {
+            self.update_screen_calibration(state);
+            self.last_state = Some(state.clone());
+            return;
+        }
+
+        // --- In gamepad output mode the controller drives the virtual gamepad instead ---
+        if self.is_gamepad_output() {
+            self.update_gamepad(state);
+            self.last_state = Some(state.clone());
+            return;
+        }
+
         let last_state_data = self
             .last_state
             .as_ref()
             .map(|last| (last.buttons.clone(), last.touchpad.clone(), last.timestamp));
 
         if let Some((last_buttons, last_touchpad, last_timestamp)) = last_state_data {
-            // --- Home button double-click detection to toggle mouse mode ---
-            if state.buttons.home && !last_buttons.home {
-                const DOUBLE_CLICK_WINDOW_MS: u64 = 300;
-                let now = state.timestamp;
-
-                if let Some(last_press_time) = self.home_button_last_press_time {
-                    if now.saturating_sub(last_press_time) < DOUBLE_CLICK_WINDOW_MS {
-                        self.mouse_config.mode = match self.mouse_config.mode {
-                            MouseMode::AirMouse => MouseMode::Touchpad,
-                            MouseMode::Touchpad => MouseMode::AirMouse,
-                        };
-                        self.home_button_last_press_time = None; // Reset timer
-                    } else {
-                        self.home_button_last_press_time = Some(now);
-                    }
-                } else {
-                    self.home_button_last_press_time = Some(now);
-                }
-            }
-
             // --- Step 1: Handle button presses (common to all modes) ---
-            self.handle_buttons(&state.buttons, &last_buttons);
+            self.handle_buttons(&state.buttons, &last_buttons, &state.touchpad);
+            if ControllerButton::ALL
+                .iter()
+                .any(|&button| state.buttons.is_pressed(button) != last_buttons.is_pressed(button))
+            {
+                self.start_click_freeze();
+            }
+            // Movement continues from where the pointer would be without the freeze.
+            if let Some(freeze) = self.click_freeze {
+                (self.target_screen_x, self.target_screen_y) = freeze.shadow;
+            }
 
             // --- Step 2: Handle movement based on the current mode ---
             let delta_t = (state.timestamp - last_timestamp) as f32;
 
+            let is_tilt_scrolling = self
+                .mouse_config
+                .tilt_scroll
+                .button
+                .is_some_and(|button| state.buttons.is_pressed(button));
+            if !is_tilt_scrolling {
+                self.tilt_scroll_anchor = None;
+                self.tilt_scroll_remainder = (0.0, 0.0);
+            } else if self.tilt_scroll_anchor.is_none() {
+                self.tilt_scroll_anchor = Some(state.orientation);
+            }
+
+            let is_radial_menu_held = self
+                .keymap_config
+                .radial_menu
+                .button
+                .is_some_and(|button| state.buttons.is_pressed(button));
+            match (is_radial_menu_held, self.radial_menu.is_some()) {
+                (true, false) => self.open_radial_menu(&state.orientation),
+                (false, true) => self.close_radial_menu(true),
+                _ => {}
+            }
+
             match self.mouse_config.mode {
+                // The radial menu takes over the touchpad and orientation while it is open.
+                _ if self.radial_menu.is_some() => {
+                    self.is_precision_mode_active = false;
+                    self.is_air_mouse_active = false;
+                    self.update_radial_menu(state);
+                }
+                // Tilt scrolling takes over movement in every mode while its button is held.
+                _ if is_tilt_scrolling => {
+                    self.is_precision_mode_active = false;
+                    self.is_air_mouse_active = false;
+                    let delta_t_ms = state.timestamp.saturating_sub(last_timestamp);
+                    if delta_t_ms > 0 {
+                        let last_orientation = self.last_state.as_ref().unwrap().orientation;
+                        self.handle_tilt_scroll(
+                            &state.orientation,
+                            &last_orientation,
+                            delta_t_ms as f32 / 1000.0,
+                        );
+                    }
+                }
                 MouseMode::AirMouse => {
                     // --- Air Mouse Mode Logic ---
 
@@ -158,45 +888,509 @@ impl MouseMapper {
                     self.handle_touchpad_movement(&state.touchpad, &last_touchpad, delta_t);
                     self.is_air_mouse_active = false;
                 }
+                MouseMode::GyroMouse => {
+                    // --- Gyro Mouse Mode Logic ---
+                    // Relative movement from angular velocity; the touchpad is not used for pointing.
+                    self.is_precision_mode_active = false;
+                    let delta_t_ms = state.timestamp.saturating_sub(last_timestamp);
+                    if delta_t_ms > 0 {
+                        self.handle_gyro_mouse_movement(
+                            &state.orientation,
+                            &state.gyroscope,
+                            delta_t_ms as f32 / 1000.0,
+                        );
+                    }
+                }
+                MouseMode::GyroAim => {
+                    // --- Gyro Aim Mode Logic ---
+                    // Raw relative deltas turn a game camera; there is no pointer target to track.
+                    self.is_precision_mode_active = false;
+                    self.is_air_mouse_active = false;
+                    let delta_t_ms = state.timestamp.saturating_sub(last_timestamp);
+                    self.handle_gyro_aim(state, delta_t_ms as f32 / 1000.0);
+                }
+                MouseMode::Handwriting => {
+                    // --- Handwriting Mode Logic ---
+                    // The touchpad draws strokes instead of moving the pointer.
+                    self.is_precision_mode_active = false;
+                    self.is_air_mouse_active = false;
+                    self.handle_handwriting(&state.touchpad);
+                }
+                MouseMode::Chord => {
+                    // --- Chord Mode Logic ---
+                    // Buttons and touchpad clicks type chords; the pointer stays still.
+                    self.is_precision_mode_active = false;
+                    self.is_air_mouse_active = false;
+                }
+                MouseMode::Scanning => {
+                    // --- Scanning Mode Logic ---
+                    // The pointer only moves through scanning actions, driven by `binding_tick`.
+                    self.is_precision_mode_active = false;
+                    self.is_air_mouse_active = false;
+                }
+                MouseMode::TiltKeys => {
+                    // --- Tilt Keys Mode Logic ---
+                    // Orientation presses keys instead of moving the pointer.
+                    self.is_precision_mode_active = false;
+                    self.is_air_mouse_active = false;
+                    self.handle_tilt_keys(&state.orientation);
+                }
             }
+
+            // --- Step 3: Hold the pointer still while a click settles ---
+            self.apply_click_freeze();
         } else {
             // Handle button presses for the very first frame.
             let default_buttons = ButtonState::default();
-            self.handle_buttons(&state.buttons, &default_buttons);
+            self.handle_buttons(&state.buttons, &default_buttons, &state.touchpad);
         }
 
-        // --- Step 3: Update the last state for the next frame ---
+        // --- Step 4: Update the last state for the next frame ---
         self.last_state = Some(state.clone());
     }
 
     /// Handles button state changes by comparing the current state to the last one.
-    fn handle_buttons(&mut self, current: &ButtonState, last: &ButtonState) {
-        let mapping = self.keymap_config.clone();
-
-        // Helper closure to process a single button's state change
-        let mut process_change = |is_pressed: bool, was_pressed: bool, key_map: &Option<String>| {
-            if let Some(key) = key_map {
-                if is_pressed && !was_pressed {
-                    // State changed from UP to DOWN: Press the key
-                    if let Err(e) = self.press_key(key) {
-                        eprintln!("Failed to press key '{}': {:?}", key, e);
+    /// In chord mode, chord buttons are collected instead and typed once they are all released.
+    fn handle_buttons(
+        &mut self,
+        current: &ButtonState,
+        last: &ButtonState,
+        touchpad: &TouchpadState,
+    ) {
+        // While aiming, the ratchet button only pauses gyro output.
+        let ratchet_button = match self.mouse_config.mode {
+            MouseMode::GyroAim => self.mouse_config.gyro_aim.ratchet_button,
+            _ => None,
+        };
+        let tilt_scroll_button = self.mouse_config.tilt_scroll.button;
+        let radial_menu_button = self.keymap_config.radial_menu.button;
+
+        for button in ControllerButton::ALL {
+            let is_pressed = current.is_pressed(button);
+            let was_pressed = last.is_pressed(button);
+            if is_pressed != was_pressed
+                && Some(button) != ratchet_button
+                && Some(button) != tilt_scroll_button
+                && Some(button) != radial_menu_button
+            {
+                if self.keymap_config.pause_chord.contains(&button) {
+                    self.handle_pause_chord_edge(button, is_pressed, touchpad);
+                } else {
+                    self.route_button_edge(button, is_pressed, touchpad);
+                }
+            }
+        }
+
+        let is_chord_held = ControllerButton::ALL.iter().any(|&button| {
+            current.is_pressed(button) && Self::chord_key(button, touchpad).is_some()
+        });
+        if !self.chord.is_empty() && !is_chord_held {
+            let chord = std::mem::take(&mut self.chord);
+            if let Some(key) = self.keymap_config.chord_output(&chord) {
+                self.press_binding(&key);
+                self.release_binding(&key);
+            }
+        }
+    }
+
+    /// Sends a button edge to whatever currently consumes it: the scanning switch, the chord being
+    /// typed, the mode-cycle gesture or the button's own binding.
+    fn route_button_edge(
+        &mut self,
+        button: ControllerButton,
+        is_pressed: bool,
+        touchpad: &TouchpadState,
+    ) {
+        let is_chord_mode = self.mouse_config.mode == MouseMode::Chord;
+        let scan_button = match self.mouse_config.mode {
+            MouseMode::Scanning => Some(self.mouse_config.scanning.button),
+            _ => None,
+        };
+
+        let chord_key = Self::chord_key(button, touchpad).filter(|_| is_chord_mode);
+        if Some(button) == scan_button {
+            if is_pressed {
+                self.select_scan_action();
+            }
+        } else if let Some(chord_key) = chord_key {
+            if is_pressed && !self.chord.contains(&chord_key) {
+                self.chord.push(chord_key);
+            }
+        } else if Some(button) == self.keymap_config.mode_cycle.button() {
+            self.handle_mode_gesture_edge(button, is_pressed);
+        } else {
+            self.handle_button_edge(button, is_pressed);
+        }
+    }
+
+    /// Returns the actions of the scanning layer the user is in, or `None` if the layer path
+    /// no longer matches the configured actions.
+    fn scan_layer_actions(&self, layers: &[usize]) -> Option<Vec<ScanAction>> {
+        let mut actions = &self.mouse_config.scanning.actions;
+        for &index in layers {
+            match actions.get(index) {
+                Some(ScanAction::Layer { actions: layer, .. }) => actions = layer,
+                _ => return None,
+            }
+        }
+        Some(actions.clone())
+    }
+
+    /// Reports the scanning highlight to the frontend.
+    fn report_scan_highlight(&self) {
+        let Some(scan) = &self.scanning else {
+            return;
+        };
+        let actions = self.scan_layer_actions(&scan.layers).unwrap_or_default();
+        self.emit_event(
+            "scan-highlight",
+            ScanHighlight {
+                actions: actions.iter().map(ScanAction::label).collect(),
+                index: scan.highlighted,
+                moving: scan.moving.is_some(),
+            },
+        );
+    }
+
+    /// Advances the scanning highlight when its interval is up and glides the pointer for move actions.
+    fn scanning_tick(&mut self, now: Instant) {
+        if self.mouse_config.mode != MouseMode::Scanning {
+            self.stop_scanning();
+            return;
+        }
+        let interval = Duration::from_millis(self.mouse_config.scanning.interval_ms.max(1));
+        let Some(scan) = &mut self.scanning else {
+            self.scanning = Some(ScanState {
+                layers: Vec::new(),
+                highlighted: 0,
+                next_step: now + interval,
+                moving: None,
+                last_tick: now,
+                remainder: (0.0, 0.0),
+            });
+            self.report_scan_highlight();
+            return;
+        };
+
+        let elapsed = now.duration_since(scan.last_tick).as_secs_f32();
+        scan.last_tick = now;
+
+        if let Some(direction) = scan.moving {
+            let distance = self.mouse_config.scanning.pointer_speed * elapsed;
+            let (dx, dy) = match direction {
+                ScanDirection::Up => (0.0, -distance),
+                ScanDirection::Down => (0.0, distance),
+                ScanDirection::Left => (-distance, 0.0),
+                ScanDirection::Right => (distance, 0.0),
+            };
+            let (total_x, total_y) = (dx + scan.remainder.0, dy + scan.remainder.1);
+            scan.remainder = (total_x.fract(), total_y.fract());
+            let (dx, dy) = (total_x.trunc() as i32, total_y.trunc() as i32);
+            if dx != 0 || dy != 0 {
+                if let Err(e) = self.backend.move_mouse(dx, dy, Coordinate::Rel) {
+                    eprintln!("Failed to move mouse: {:?}", e);
+                }
+            }
+            return;
+        }
+
+        if now < scan.next_step {
+            return;
+        }
+        let layers = scan.layers.clone();
+        let Some(actions) = self.scan_layer_actions(&layers) else {
+            // The action list changed under the current layer; start over from the top.
+            self.scanning = None;
+            return;
+        };
+        if let Some(scan) = &mut self.scanning {
+            scan.highlighted = (scan.highlighted + 1) % actions.len().max(1);
+            scan.next_step = now + interval;
+        }
+        self.report_scan_highlight();
+    }
+
+    /// Ends switch scanning and tells the frontend to hide the highlight.
+    fn stop_scanning(&mut self) {
+        if self.scanning.take().is_some() {
+            self.emit_event("scan-highlight", None::<ScanHighlight>);
+        }
+    }
+
+    /// Runs the highlighted scanning action, or stops a pointer glide.
+    /// Scanning restarts from the first action of the resulting layer.
+    fn select_scan_action(&mut self) {
+        let Some(scan) = self.scanning.clone() else {
+            return;
+        };
+        let mut layers = scan.layers.clone();
+        let mut moving = None;
+
+        if scan.moving.is_none() {
+            let action = self
+                .scan_layer_actions(&layers)
+                .and_then(|actions| actions.get(scan.highlighted).cloned());
+            match action {
+                Some(ScanAction::Move { direction }) => moving = Some(direction),
+                Some(ScanAction::Press { binding }) => {
+                    self.press_binding(&binding);
+                    self.release_binding(&binding);
+                }
+                Some(ScanAction::Layer { .. }) => layers.push(scan.highlighted),
+                Some(ScanAction::Back) => {
+                    layers.pop();
+                }
+                None => layers.clear(),
+            }
+        }
+
+        let now = self.now();
+        let interval = Duration::from_millis(self.mouse_config.scanning.interval_ms.max(1));
+        self.scanning = Some(ScanState {
+            layers,
+            highlighted: 0,
+            next_step: now + interval,
+            moving,
+            last_tick: now,
+            remainder: (0.0, 0.0),
+        });
+        self.report_scan_highlight();
+    }
+
+    /// Returns the chord key a button press counts as; touchpad clicks count by quadrant.
+    fn chord_key(button: ControllerButton, touchpad: &TouchpadState) -> Option<ChordKey> {
+        match button {
+            ControllerButton::Trigger => Some(ChordKey::Trigger),
+            ControllerButton::Back => Some(ChordKey::Back),
+            ControllerButton::VolumeUp => Some(ChordKey::VolumeUp),
+            ControllerButton::VolumeDown => Some(ChordKey::VolumeDown),
+            ControllerButton::Touchpad => {
+                let (x, y) = (touchpad.x - 0.5, touchpad.y - 0.5);
+                Some(if x.abs() > y.abs() {
+                    if x < 0.0 {
+                        ChordKey::TouchpadLeft
+                    } else {
+                        ChordKey::TouchpadRight
                     }
-                } else if !is_pressed && was_pressed {
-                    // State changed from DOWN to UP: Release the key
-                    if let Err(e) = self.release_key(key) {
-                        eprintln!("Failed to release key '{}': {:?}", key, e);
+                } else if y < 0.0 {
+                    ChordKey::TouchpadUp
+                } else {
+                    ChordKey::TouchpadDown
+                })
+            }
+            ControllerButton::Home => None,
+        }
+    }
+
+    /// Runs the mode-cycle gesture state machine for an edge of the gesture button.
+    /// The button's own binding is deferred until the gesture is ruled out, and suppressed if it completes.
+    fn handle_mode_gesture_edge(&mut self, button: ControllerButton, is_pressed: bool) {
+        let now = self.now();
+
+        // A press that outlived the gesture window is a normal press; let it through first.
+        self.flush_mode_gesture(now);
+
+        if !is_pressed {
+            if self.mode_gesture_swallow_release {
+                self.mode_gesture_swallow_release = false;
+            } else if let Some(pending) = self.mode_gesture_pending.as_mut() {
+                pending.released = true;
+                if let ModeCycleGesture::Hold { .. } = self.keymap_config.mode_cycle {
+                    // Released before the hold completed: it was a plain click.
+                    self.mode_gesture_pending = None;
+                    self.handle_button_edge(button, true);
+                    self.handle_button_edge(button, false);
+                }
+            } else {
+                self.handle_button_edge(button, false);
+            }
+            return;
+        }
+
+        match self.keymap_config.mode_cycle {
+            ModeCycleGesture::DoubleClick { .. } if self.mode_gesture_pending.is_some() => {
+                // Second press inside the window completes the double-click.
+                self.mode_gesture_pending = None;
+                self.mode_gesture_swallow_release = true;
+                self.cycle_mode();
+            }
+            _ => {
+                self.mode_gesture_pending = Some(PendingGesturePress {
+                    since: now,
+                    released: false,
+                });
+            }
+        }
+    }
+
+    /// Resolves a deferred gesture press once its window has elapsed.
+    /// For a double-click this replays the deferred binding; for a hold it cycles the mode.
+    fn flush_mode_gesture(&mut self, now: Instant) {
+        let Some(pending) = self.mode_gesture_pending else {
+            return;
+        };
+        let Some(button) = self.keymap_config.mode_cycle.button() else {
+            self.mode_gesture_pending = None;
+            return;
+        };
+
+        match self.keymap_config.mode_cycle {
+            ModeCycleGesture::DoubleClick { window_ms, .. } => {
+                if now.duration_since(pending.since) >= Duration::from_millis(window_ms) {
+                    self.mode_gesture_pending = None;
+                    self.handle_button_edge(button, true);
+                    if pending.released {
+                        self.handle_button_edge(button, false);
                     }
                 }
             }
+            ModeCycleGesture::Hold { hold_ms, .. } => {
+                if now.duration_since(pending.since) >= Duration::from_millis(hold_ms) {
+                    self.mode_gesture_pending = None;
+                    self.mode_gesture_swallow_release = true;
+                    self.cycle_mode();
+                }
+            }
+            ModeCycleGesture::Disabled => {
+                self.mode_gesture_pending = None;
+            }
+        }
+    }
+
+    /// Switches to the mode after the current one in `enabled_modes`.
+    fn cycle_mode(&mut self) {
+        let modes = &self.mouse_config.enabled_modes;
+        if modes.is_empty() {
+            return;
+        }
+        let next_mode = match modes.iter().position(|&m| m == self.mouse_config.mode) {
+            Some(index) => modes[(index + 1) % modes.len()],
+            None => modes[0],
+        };
+        self.set_mode(next_mode);
+    }
+
+    /// Applies a single button press or release according to the binding's behaviour.
+    fn handle_button_edge(&mut self, button: ControllerButton, is_pressed: bool) {
+        let Some(key) = self.keymap_config.binding(button).map(str::to_string) else {
+            return;
         };
+        let index = button.index();
+        let now = self.now();
 
-        // Process each button
-        process_change(current.trigger, last.trigger, &mapping.trigger);
-        process_change(current.home, last.home, &mapping.home);
-        process_change(current.back, last.back, &mapping.back);
-        process_change(current.volume_up, last.volume_up, &mapping.volume_up);
-        process_change(current.volume_down, last.volume_down, &mapping.volume_down);
-        process_change(current.touchpad, last.touchpad, &mapping.touchpad);
+        match self.keymap_config.behaviors.get(button) {
+            ButtonBehavior::Normal => {
+                if is_pressed {
+                    self.press_binding(&key);
+                } else {
+                    self.release_binding(&key);
+                }
+            }
+            ButtonBehavior::Toggle => {
+                // Only presses flip the latch; releases of the physical button are ignored.
+                if is_pressed {
+                    if self.binding_runtime[index].toggled {
+                        self.release_binding(&key);
+                    } else {
+                        self.press_binding(&key);
+                    }
+                    self.binding_runtime[index].toggled = !self.binding_runtime[index].toggled;
+                }
+            }
+            ButtonBehavior::Repeat { delay_ms, .. } => {
+                if is_pressed {
+                    self.press_binding(&key);
+                    self.binding_runtime[index].next_fire =
+                        Some(now + Duration::from_millis(delay_ms));
+                } else {
+                    self.binding_runtime[index].next_fire = None;
+                    self.release_binding(&key);
+                }
+            }
+            ButtonBehavior::Turbo { rate_hz } => {
+                if is_pressed {
+                    self.press_binding(&key);
+                    self.binding_runtime[index].turbo_down = true;
+                    self.binding_runtime[index].next_fire =
+                        Some(now + Self::rate_period(rate_hz) / 2);
+                } else {
+                    self.binding_runtime[index].next_fire = None;
+                    if self.binding_runtime[index].turbo_down {
+                        self.release_binding(&key);
+                        self.binding_runtime[index].turbo_down = false;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Advances repeat and turbo bindings whose next step is due, and resolves deferred gesture presses.
+    /// This is driven by the mapper thread's tick loop rather than by blocking sleeps.
+    pub fn binding_tick(&mut self) {
+        if self.is_paused() {
+            return;
+        }
+
+        let now = self.now();
+        self.flush_mode_gesture(now);
+        self.flush_pause_chord(now);
+        self.scanning_tick(now);
+
+        for button in ControllerButton::ALL {
+            let index = button.index();
+            match self.binding_runtime[index].next_fire {
+                Some(next_fire) if now >= next_fire => {}
+                _ => continue,
+            }
+            let Some(key) = self.keymap_config.binding(button).map(str::to_string) else {
+                self.binding_runtime[index].next_fire = None;
+                continue;
+            };
+
+            match self.keymap_config.behaviors.get(button) {
+                ButtonBehavior::Repeat { rate_hz, .. } => {
+                    // Re-fire as a fresh click so both keys and one-shot actions like scroll repeat.
+                    self.release_binding(&key);
+                    self.press_binding(&key);
+                    self.binding_runtime[index].next_fire = Some(now + Self::rate_period(rate_hz));
+                }
+                ButtonBehavior::Turbo { rate_hz } => {
+                    if self.binding_runtime[index].turbo_down {
+                        self.release_binding(&key);
+                    } else {
+                        self.press_binding(&key);
+                    }
+                    self.binding_runtime[index].turbo_down =
+                        !self.binding_runtime[index].turbo_down;
+                    self.binding_runtime[index].next_fire =
+                        Some(now + Self::rate_period(rate_hz) / 2);
+                }
+                // The behaviour was changed while the button was held; stop scheduling.
+                ButtonBehavior::Normal | ButtonBehavior::Toggle => {
+                    self.binding_runtime[index].next_fire = None;
+                }
+            }
+        }
+    }
+
+    /// Converts a rate in Hz to the period between steps.
+    fn rate_period(rate_hz: f32) -> Duration {
+        const MIN_RATE_HZ: f32 = 0.1;
+        Duration::from_secs_f32(1.0 / rate_hz.max(MIN_RATE_HZ))
+    }
+
+    /// Presses a binding, logging any failure.
+    fn press_binding(&mut self, key: &str) {
+        if let Err(e) = self.press_key(key) {
+            eprintln!("Failed to press key '{}': {:?}", key, e);
+        }
+    }
+
+    /// Releases a binding, logging any failure.
+    fn release_binding(&mut self, key: &str) {
+        if let Err(e) = self.release_key(key) {
+            eprintln!("Failed to release key '{}': {:?}", key, e);
+        }
     }
 
     /// Determines if a key string is a modifier key.
@@ -246,55 +1440,211 @@ impl MouseMapper {
         }
     }
 
-    /// Presses a key or mouse button based on string identifier.
-    fn press_key(&mut self, key_str: &str) -> Result<()> {
-        // Check if any part of the key string requires the main thread.
-        let needs_main_thread = key_str
-            .split('+')
-            .any(|part| matches!(Self::string_to_key(part.trim()), Some(Key::Unicode(_))));
+    /// Performs an action handled by the mapper itself.
+    fn run_mapper_action(&mut self, action: MapperAction) {
+        match action {
+            MapperAction::NextDisplay => self.jump_to_next_display(),
+            MapperAction::CycleDwellClick => {
+                self.dwell_click_kind = self.dwell_click_kind.next();
+                self.emit_event("dwell-click-kind-changed", self.dwell_click_kind);
+            }
+        }
+    }
 
-        if needs_main_thread {
-            // If so, execute the entire operation on the main thread.
-            let app_handle = self.app_handle.clone();
-            let key_string = key_str.to_string();
-            app_handle.run_on_main_thread(move || {
-                let mut enigo = Enigo::new(&Settings::default()).unwrap();
-                Self::execute_key_sequence(&mut enigo, &key_string, Press).unwrap();
-            })?;
+    /// Returns the current display layout, re-querying it if the cached copy is stale.
+    /// Keeps the previous layout if the backend cannot report one.
+    fn display_layout(&mut self) -> Vec<DisplayRect> {
+        let is_stale = self
+            .displays_refreshed_at
+            .is_none_or(|refreshed_at| refreshed_at.elapsed() >= DISPLAY_REFRESH_INTERVAL);
+
+        if is_stale {
+            let displays = self.backend.displays().unwrap_or_else(|e| {
+                eprintln!("Failed to query display layout: {:?}", e);
+                Vec::new()
+            });
+            if !displays.is_empty() {
+                self.displays = displays;
+            } else if self.displays.is_empty() {
+                // The mapping code needs at least one display; assume a 1080p one at the origin.
+                self.displays = vec![DisplayRect::new(0, 0, 1920, 1080)];
+            }
+            self.displays_refreshed_at = Some(self.now());
+        }
+
+        self.displays.clone()
+    }
+
+    /// Returns the displays the absolute air mouse spans: the configured subset, or all displays.
+    fn air_mouse_displays(&mut self) -> Vec<DisplayRect> {
+        let displays = self.display_layout();
+        let selected: Vec<DisplayRect> = self
+            .mouse_config
+            .air_mouse_displays
+            .iter()
+            .filter_map(|&index| displays.get(index).copied())
+            .collect();
+
+        if selected.is_empty() {
+            displays
         } else {
-            // Otherwise, execute on the current thread.
-            Self::execute_key_sequence(&mut self.enigo, key_str, Press)?;
+            selected
         }
-        Ok(())
+    }
+
+    /// Returns the configured mapping region as a display rectangle, if any.
+    /// A window region counts as unset while its window cannot be found.
+    fn mapping_region(&self) -> Option<DisplayRect> {
+        match self.mouse_config.mapping_region.as_ref()? {
+            &MappingRegion::Rect {
+                x,
+                y,
+                width,
+                height,
+            } => Some(DisplayRect::new(x, y, width, height)),
+            MappingRegion::Window { .. } => {
+                self.window_tracker.as_ref().and_then(WindowTracker::bounds)
+            }
+        }
+    }
+
+    /// Returns the area absolute positioning spans: the mapping region, or else the selected displays.
+    fn absolute_mapping_area(&mut self) -> Vec<DisplayRect> {
+        match self.mapping_region() {
+            Some(region) => vec![region],
+            None => self.air_mouse_displays(),
+        }
+    }
+
+    /// Moves the pointer to the centre of the display after the one it is currently on.
+    fn jump_to_next_display(&mut self) {
+        let displays = self.display_layout();
+        let next_index = self
+            .pointer_location()
+            .and_then(|(x, y)| display::display_index_at(&displays, x, y))
+            .map_or(0, |index| (index + 1) % displays.len());
+        let (target_x, target_y) = displays[next_index].center();
+
+        self.target_screen_x = target_x;
+        self.target_screen_y = target_y;
+        self.remainder_x = 0.0;
+        self.remainder_y = 0.0;
+        if let Err(e) = self.backend.move_mouse(target_x, target_y, Coordinate::Abs) {
+            eprintln!("Failed to move mouse to next display: {:?}", e);
+        }
+    }
+
+    /// Releases every key and button the mapper is currently holding down.
+    /// Used when the controller disconnects, the config changes, data times out or the app exits,
+    /// so the host is never left with a stuck key or mouse button.
+    pub fn release_all(&mut self) {
+        while let Some(key) = self.held_keys.last().cloned() {
+            self.release_binding(&key);
+        }
+        self.binding_runtime = Default::default();
+        self.mode_gesture_pending = None;
+        self.reset_pause_chord_deferral();
+        self.dwell_dragging = false;
+        self.tilt_key_states = Default::default();
+        self.tilt_keys_center = None;
+        self.flick = None;
+        self.flick_stick_angle = None;
+        self.close_radial_menu(false);
+        self.handwriting_stroke.clear();
+        self.chord.clear();
+        self.stop_scanning();
+        #[cfg(target_os = "linux")]
+        if let Some(gamepad) = self.gamepad.as_mut() {
+            if let Err(e) = gamepad.reset() {
+                eprintln!("Failed to reset virtual gamepad: {:?}", e);
+            }
+        }
+    }
+
+    /// Releases everything like `release_all`, but returns the releases that must run on the main
+    /// thread instead of queueing them. Used on exit, when the event loop no longer runs queued jobs.
+    pub fn release_all_for_exit(&mut self) -> Vec<MainThreadJob> {
+        self.collected_main_thread_jobs = Some(Vec::new());
+        self.release_all();
+        self.collected_main_thread_jobs.take().unwrap_or_default()
+    }
+
+    /// Presses a key or mouse button based on string identifier.
+    fn press_key(&mut self, key_str: &str) -> Result<()> {
+        // Mapper actions fire once on press and are never held.
+        if let Some(action) = MapperAction::from_binding(key_str) {
+            self.run_mapper_action(action);
+            return Ok(());
+        }
+
+        self.held_keys.push(key_str.to_string());
+
+        self.run_key_sequence(key_str, Press)
     }
 
     /// Releases a key or mouse button based on string identifier.
+    /// Keys that are not currently held (e.g. already released by `release_all`) are ignored.
     fn release_key(&mut self, key_str: &str) -> Result<()> {
-        let needs_main_thread = key_str
-            .split('+')
-            .any(|part| matches!(Self::string_to_key(part.trim()), Some(Key::Unicode(_))));
+        let Some(position) = self.held_keys.iter().rposition(|k| k == key_str) else {
+            return Ok(());
+        };
+        self.held_keys.remove(position);
+
+        self.run_key_sequence(key_str, Release)
+    }
+
+    /// Executes a key sequence on the backend, moving the whole sequence to the main thread
+    /// if any part of it requires that, so modifiers and keys stay in order.
+    fn run_key_sequence(&mut self, key_str: &str, direction: Direction) -> Result<()> {
+        let scroll_amount = self.mouse_config.scroll_amount;
+        let needs_main_thread = key_str.split('+').any(|part| {
+            Self::string_to_key(part.trim()).is_some_and(|key| self.backend.needs_main_thread(&key))
+        });
 
         if needs_main_thread {
-            let app_handle = self.app_handle.clone();
             let key_string = key_str.to_string();
-            app_handle.run_on_main_thread(move || {
-                let mut enigo = Enigo::new(&Settings::default()).unwrap();
-                Self::execute_key_sequence(&mut enigo, &key_string, Release).unwrap();
-            })?;
+            let job: MainThreadJob = Box::new(move |backend| {
+                Self::execute_key_sequence(backend, &key_string, direction, scroll_amount)
+            });
+            match self.collected_main_thread_jobs.as_mut() {
+                Some(jobs) => {
+                    jobs.push(job);
+                    Ok(())
+                }
+                None => self.backend.run_on_main_thread(job),
+            }
         } else {
-            Self::execute_key_sequence(&mut self.enigo, key_str, Release)?;
+            Self::execute_key_sequence(self.backend.as_mut(), key_str, direction, scroll_amount)
+        }
+    }
+
+    /// Converts a scroll action string to a signed scroll length and axis.
+    /// Positive lengths scroll down/right, negative lengths scroll up/left.
+    fn string_to_scroll(key: &str, scroll_amount: i32) -> Option<(i32, Axis)> {
+        match key.to_lowercase().as_str() {
+            "scroll up" | "wheel up" => Some((-scroll_amount, Axis::Vertical)),
+            "scroll down" | "wheel down" => Some((scroll_amount, Axis::Vertical)),
+            "scroll left" | "wheel left" => Some((-scroll_amount, Axis::Horizontal)),
+            "scroll right" | "wheel right" => Some((scroll_amount, Axis::Horizontal)),
+            _ => None,
         }
-        Ok(())
     }
 
-    /// Helper function to execute the actual key sequence on a given enigo instance.
-    fn execute_key_sequence(enigo: &mut Enigo, key_str: &str, direction: Direction) -> Result<()> {
+    /// Helper function to execute the actual key sequence on a given backend.
+    /// Scroll actions fire once per press and are ignored on release.
+    fn execute_key_sequence(
+        backend: &mut dyn InputBackend,
+        key_str: &str,
+        direction: Direction,
+        scroll_amount: i32,
+    ) -> Result<()> {
         let parts: Vec<&str> = key_str.split('+').map(|k| k.trim()).collect();
 
-        // Separate parts into modifiers, regular keys, and mouse buttons
+        // Separate parts into modifiers, regular keys, mouse buttons and scroll steps
         let mut modifier_keys = Vec::new();
         let mut action_keys = Vec::new();
         let mut mouse_buttons = Vec::new();
+        let mut scroll_steps = Vec::new();
 
         for part in parts {
             let lower_part = part.to_lowercase();
@@ -307,8 +1657,12 @@ impl MouseMapper {
                     "left" => mouse_buttons.push(Button::Left),
                     "right" => mouse_buttons.push(Button::Right),
                     "middle" => mouse_buttons.push(Button::Middle),
+                    "mouse back" | "x1" | "mouse4" => mouse_buttons.push(Button::Back),
+                    "mouse forward" | "x2" | "mouse5" => mouse_buttons.push(Button::Forward),
                     _ => {
-                        if let Some(key) = Self::string_to_key(&lower_part) {
+                        if let Some(scroll) = Self::string_to_scroll(&lower_part, scroll_amount) {
+                            scroll_steps.push(scroll);
+                        } else if let Some(key) = Self::string_to_key(&lower_part) {
                             action_keys.push(key);
                         }
                     }
@@ -316,71 +1670,436 @@ impl MouseMapper {
             }
         }
 
-        match direction {
-            Press => {
-                // Press all modifiers first
-                for &key in &modifier_keys {
-                    enigo.key(key, Press)?;
-                }
+        match direction {
+            Press => {
+                // Press all modifiers first
+                for &key in &modifier_keys {
+                    backend.key(key, Press)?;
+                }
+
+                // Determine the action for keys and buttons
+                let action_direction = if !modifier_keys.is_empty() {
+                    Click
+                } else {
+                    Press
+                };
+
+                // Press action keys
+                for &key in &action_keys {
+                    backend.key(key, action_direction)?;
+                }
+
+                // Press mouse buttons
+                for &button in &mouse_buttons {
+                    backend.button(button, action_direction)?;
+                }
+
+                // Scroll steps are one-shot actions, so they fire on press only
+                for &(length, axis) in &scroll_steps {
+                    backend.scroll(length, axis)?;
+                }
+            }
+            Release => {
+                // Release action keys and mouse buttons only if they were pressed without modifiers
+                if modifier_keys.is_empty() {
+                    for &key in action_keys.iter().rev() {
+                        backend.key(key, Release)?;
+                    }
+                    for &button in mouse_buttons.iter().rev() {
+                        backend.button(button, Release)?;
+                    }
+                }
+
+                // Release all modifiers last, in reverse order
+                for &key in modifier_keys.iter().rev() {
+                    backend.key(key, Release)?;
+                }
+            }
+            Click => {
+                // This case handles a full click sequence, often used for actions combined with modifiers.
+                // Press modifiers
+                for &key in &modifier_keys {
+                    backend.key(key, Press)?;
+                }
+
+                // Click action keys
+                for &key in &action_keys {
+                    backend.key(key, Click)?;
+                }
+
+                // Click mouse buttons
+                for &button in &mouse_buttons {
+                    backend.button(button, Click)?;
+                }
+
+                // Scroll
+                for &(length, axis) in &scroll_steps {
+                    backend.scroll(length, axis)?;
+                }
+
+                // Release modifiers
+                for &key in modifier_keys.iter().rev() {
+                    backend.key(key, Release)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Transforms a controller orientation to the display coordinate system,
+    /// where x points forward, z points up, yaw moves horizontally and pitch vertically.
+    fn to_display_frame(orientation: &UnitQuaternion<f64>) -> UnitQuaternion<f64> {
+        let transformed_quat =
+            nalgebra::Quaternion::new(orientation.w, orientation.j, orientation.i, -orientation.k);
+        UnitQuaternion::new_normalize(transformed_quat)
+    }
+
+    /// Converts body-frame angular velocity into yaw and pitch rates (in degrees per second)
+    /// measured against the horizon, so rolling the controller does not skew the pointer direction.
+    fn roll_compensated_rates(
+        orientation: &UnitQuaternion<f64>,
+        gyroscope: &Vector3<f64>,
+    ) -> (f32, f32) {
+        // Apply the same axis remapping as `to_display_frame` to the angular velocity vector.
+        let transformed_orientation = Self::to_display_frame(orientation);
+        let body_rate = Vector3::new(gyroscope.y, gyroscope.x, -gyroscope.z);
+        let world_rate = transformed_orientation * body_rate;
+
+        // Yaw is rotation about the vertical axis; pitch is rotation about the horizontal axis
+        // perpendicular to where the controller points. Only gravity matters, not heading.
+        let up = Vector3::z();
+        let forward = transformed_orientation * Vector3::x();
+        let yaw_rate = world_rate.dot(&up);
+        let pitch_rate = up
+            .cross(&forward)
+            .try_normalize(1.0e-6)
+            .map_or(0.0, |lateral| world_rate.dot(&lateral));
+
+        (yaw_rate.to_degrees() as f32, pitch_rate.to_degrees() as f32)
+    }
+
+    /// Handles relative mouse movement in gyro mouse mode, like a Wii/Switch pointer.
+    fn handle_gyro_mouse_movement(
+        &mut self,
+        orientation: &UnitQuaternion<f64>,
+        gyroscope: &Vector3<f64>,
+        delta_t_s: f32,
+    ) {
+        let (yaw_rate, pitch_rate) = Self::roll_compensated_rates(orientation, gyroscope);
+        let speed = (yaw_rate.powi(2) + pitch_rate.powi(2)).sqrt();
+
+        // Below the deadzone the controller is considered still; this hides sensor noise.
+        self.is_air_mouse_active = speed > self.mouse_config.gyro_mouse_deadzone;
+        if !self.is_air_mouse_active {
+            return;
+        }
+
+        let gain = self.gyro_mouse_gain(speed);
+        let dx = yaw_rate * delta_t_s * gain;
+        let dy = -pitch_rate * delta_t_s * gain;
+        self.apply_relative_movement(dx, dy);
+    }
+
+    /// Opens the radial menu and tells the frontend which slices to draw.
+    fn open_radial_menu(&mut self, orientation: &UnitQuaternion<f64>) {
+        self.radial_menu = Some(RadialMenuState {
+            anchor: *orientation,
+            highlighted: None,
+        });
+        self.emit_event(
+            "radial-menu-opened",
+            self.keymap_config.radial_menu.slices.clone(),
+        );
+    }
+
+    /// Highlights the slice the touchpad or tilt points at.
+    /// The touchpad wins while it is touched; otherwise the tilt since opening the menu is used.
+    fn update_radial_menu(&mut self, state: &ControllerState) {
+        let Some(menu) = self.radial_menu else {
+            return;
+        };
+        let config = &self.keymap_config.radial_menu;
+
+        // Selection direction with up being positive y and right positive x.
+        let (x, y) = (state.touchpad.x - 0.5, 0.5 - state.touchpad.y);
+        let direction = if state.touchpad.touched {
+            ((x.powi(2) + y.powi(2)).sqrt() * 2.0 >= config.touchpad_deadzone).then_some((x, y))
+        } else {
+            let delta_orientation = Self::to_display_frame(&menu.anchor).inverse()
+                * Self::to_display_frame(&state.orientation);
+            let (roll, pitch, _yaw) = delta_orientation.euler_angles();
+            let (roll, pitch) = (roll.to_degrees() as f32, pitch.to_degrees() as f32);
+            ((roll.powi(2) + pitch.powi(2)).sqrt() >= config.tilt_deadzone).then_some((roll, pitch))
+        };
+
+        let slice_count = config.slices.len();
+        let highlighted = direction.filter(|_| slice_count > 0).map(|(x, y)| {
+            // Slice 0 is centred on the top; slices run clockwise.
+            let slice_angle = 360.0 / slice_count as f32;
+            let angle = x.atan2(y).to_degrees().rem_euclid(360.0);
+            ((angle / slice_angle).round() as usize) % slice_count
+        });
+
+        if highlighted != menu.highlighted {
+            self.radial_menu = Some(RadialMenuState {
+                highlighted,
+                ..menu
+            });
+            self.emit_event("radial-menu-highlight", highlighted);
+        }
+    }
+
+    /// Closes the radial menu, running the highlighted slice's binding if `execute` is set.
+    fn close_radial_menu(&mut self, execute: bool) {
+        let Some(menu) = self.radial_menu.take() else {
+            return;
+        };
+        let selected = menu.highlighted.filter(|_| execute);
+        self.emit_event("radial-menu-closed", selected);
+
+        let binding = selected
+            .and_then(|index| self.keymap_config.radial_menu.slices.get(index))
+            .cloned();
+        if let Some(key) = binding {
+            self.press_binding(&key);
+            self.release_binding(&key);
+        }
+    }
+
+    /// Scrolls by pitching the controller and zooms or scrolls sideways by twisting it.
+    fn handle_tilt_scroll(
+        &mut self,
+        orientation: &UnitQuaternion<f64>,
+        last_orientation: &UnitQuaternion<f64>,
+        delta_t_s: f32,
+    ) {
+        let config = self.mouse_config.tilt_scroll.clone();
+
+        // Rotation since the last frame (rate) or since the button was pressed (angle),
+        // measured in the controller's own frame so pitch and roll stay separate.
+        let reference = match config.input {
+            TiltScrollInput::Rate => *last_orientation,
+            TiltScrollInput::Angle => self.tilt_scroll_anchor.unwrap_or(*last_orientation),
+        };
+        let delta_orientation =
+            Self::to_display_frame(&reference).inverse() * Self::to_display_frame(orientation);
+        let (roll, pitch, _yaw) = delta_orientation.euler_angles();
+        let (mut roll, mut pitch) = (roll.to_degrees() as f32, pitch.to_degrees() as f32);
+        if config.input == TiltScrollInput::Rate {
+            roll /= delta_t_s;
+            pitch /= delta_t_s;
+        }
+
+        // Wheel steps per second past the deadzone, shaped by the acceleration exponent.
+        let steps_per_second = |value: f32| {
+            let magnitude = (value.abs() - config.deadzone).max(0.0);
+            config.sensitivity * magnitude.powf(config.acceleration.max(0.1)) * value.signum()
+        };
+
+        // Tipping the controller up scrolls up; positive lengths scroll down.
+        let vertical = -steps_per_second(pitch) * delta_t_s + self.tilt_scroll_remainder.0;
+        let twist = match config.twist {
+            TwistAction::None => 0.0,
+            // Twisting right zooms in, i.e. Ctrl+scroll up.
+            TwistAction::Zoom => -steps_per_second(roll) * delta_t_s,
+            TwistAction::HorizontalScroll => steps_per_second(roll) * delta_t_s,
+        } + self.tilt_scroll_remainder.1;
+        self.tilt_scroll_remainder = (vertical.fract(), twist.fract());
+
+        let (vertical, twist) = (vertical.trunc() as i32, twist.trunc() as i32);
+        if vertical != 0 {
+            if let Err(e) = self.backend.scroll(vertical, Axis::Vertical) {
+                eprintln!("Failed to scroll: {:?}", e);
+            }
+        }
+        if twist != 0 {
+            let result = match config.twist {
+                TwistAction::Zoom => self.zoom(twist),
+                _ => self.backend.scroll(twist, Axis::Horizontal),
+            };
+            if let Err(e) = result {
+                eprintln!("Failed to scroll: {:?}", e);
+            }
+        }
+    }
+
+    /// Scrolls with Ctrl held, which zooms in most applications.
+    fn zoom(&mut self, length: i32) -> Result<()> {
+        self.backend.key(Key::Control, Press)?;
+        let result = self.backend.scroll(length, Axis::Vertical);
+        self.backend.key(Key::Control, Release)?;
+        result
+    }
+
+    /// Turns a game camera in gyro aim mode by sending raw relative mouse deltas.
+    /// Unlike the pointer modes, movement is neither clamped to the screen nor smoothed.
+    fn handle_gyro_aim(&mut self, state: &ControllerState, delta_t_s: f32) {
+        let config = self.mouse_config.gyro_aim.clone();
+        let mut yaw_deg = 0.0;
+        let mut pitch_deg = 0.0;
+
+        let is_ratcheting = config
+            .ratchet_button
+            .is_some_and(|button| state.buttons.is_pressed(button));
+        if !is_ratcheting {
+            let (yaw_rate, pitch_rate) =
+                Self::roll_compensated_rates(&state.orientation, &state.gyroscope);
+            if (yaw_rate.powi(2) + pitch_rate.powi(2)).sqrt() > config.deadzone {
+                yaw_deg += yaw_rate * delta_t_s * config.sensitivity;
+                pitch_deg += pitch_rate * delta_t_s * config.sensitivity;
+            }
+        }
+
+        if config.flick_stick {
+            yaw_deg += self.flick_stick_turn(&state.touchpad, &config);
+        } else {
+            self.flick = None;
+            self.flick_stick_angle = None;
+        }
+
+        let total_dx = yaw_deg * config.counts_per_degree + self.remainder_x;
+        let total_dy = -pitch_deg * config.counts_per_degree + self.remainder_y;
+        let (dx, dy) = (total_dx.trunc() as i32, total_dy.trunc() as i32);
+        self.remainder_x = total_dx.fract();
+        self.remainder_y = total_dy.fract();
+
+        if dx != 0 || dy != 0 {
+            if let Err(e) = self.backend.move_mouse(dx, dy, Coordinate::Rel) {
+                eprintln!("Failed to move mouse: {:?}", e);
+            }
+        }
+    }
+
+    /// Returns the camera turn (degrees) from the touchpad flick stick for this frame.
+    /// Touching beyond the deadzone flicks towards the finger's direction, with up being straight ahead;
+    /// moving the finger around the edge then turns by the change in its direction.
+    fn flick_stick_turn(&mut self, touchpad: &TouchpadState, config: &GyroAimConfig) -> f32 {
+        let mut turn = 0.0;
+        let (x, y) = (touchpad.x - 0.5, touchpad.y - 0.5);
+
+        if touchpad.touched && (x.powi(2) + y.powi(2)).sqrt() * 2.0 >= config.flick_deadzone {
+            let angle = x.atan2(-y).to_degrees();
+            match self.flick_stick_angle {
+                Some(last_angle) => turn += wrap_degrees(angle - last_angle),
+                None => {
+                    self.flick = Some(Flick {
+                        angle,
+                        sent: 0.0,
+                        started: self.now(),
+                    })
+                }
+            }
+            self.flick_stick_angle = Some(angle);
+        } else {
+            self.flick_stick_angle = None;
+        }
+
+        // Spread the flick over the flick time so the game sees a fast turn rather than a teleport.
+        if let Some(flick) = &mut self.flick {
+            let flick_time = Duration::from_millis(config.flick_time_ms).as_secs_f32();
+            let progress = if flick_time > 0.0 {
+                (flick.started.elapsed().as_secs_f32() / flick_time).min(1.0)
+            } else {
+                1.0
+            };
+            turn += flick.angle * progress - flick.sent;
+            flick.sent = flick.angle * progress;
+            if progress >= 1.0 {
+                self.flick = None;
+            }
+        }
 
-                // Determine the action for keys and buttons
-                let action_direction = if !modifier_keys.is_empty() {
-                    Click
-                } else {
-                    Press
-                };
+        turn
+    }
 
-                // Press action keys
-                for &key in &action_keys {
-                    enigo.key(key, action_direction)?;
-                }
+    /// Returns the gyro mouse gain in pixels per degree for a rotational speed.
+    /// Without a configured curve, the gain ramps linearly from the base sensitivity at the slow speed
+    /// to `gyro_mouse_fast_multiplier` times that at the fast speed.
+    fn gyro_mouse_gain(&self, speed_dps: f32) -> f32 {
+        let config = &self.mouse_config;
+        if let Some(curve) = &config.gyro_mouse_curve {
+            return config.gyro_mouse_sensitivity * curve.gain(speed_dps);
+        }
+        let span = (config.gyro_mouse_fast_speed - config.gyro_mouse_slow_speed).max(f32::EPSILON);
+        let t = ((speed_dps - config.gyro_mouse_slow_speed) / span).clamp(0.0, 1.0);
+        config.gyro_mouse_sensitivity * (1.0 + t * (config.gyro_mouse_fast_multiplier - 1.0))
+    }
 
-                // Press mouse buttons
-                for &button in &mouse_buttons {
-                    enigo.button(button, action_direction)?;
-                }
-            }
-            Release => {
-                // Release action keys and mouse buttons only if they were pressed without modifiers
-                if modifier_keys.is_empty() {
-                    for &key in action_keys.iter().rev() {
-                        enigo.key(key, Release)?;
-                    }
-                    for &button in mouse_buttons.iter().rev() {
-                        enigo.button(button, Release)?;
-                    }
-                }
+    /// Holds the pointer at its current position after a button press or release.
+    /// A change within an active freeze extends it on the same pixel, so double-clicks land together.
+    fn start_click_freeze(&mut self) {
+        // Aiming turns a camera rather than pointing, so there is no click position to hold.
+        if self.mouse_config.click_stabilization_ms == 0
+            || self.mouse_config.mode == MouseMode::GyroAim
+        {
+            return;
+        }
+        let until = self.now() + Duration::from_millis(self.mouse_config.click_stabilization_ms);
 
-                // Release all modifiers last, in reverse order
-                for &key in modifier_keys.iter().rev() {
-                    enigo.key(key, Release)?;
-                }
+        match &mut self.click_freeze {
+            Some(freeze) => freeze.until = until,
+            None => {
+                let Some(anchor) = self.pointer_location() else {
+                    return;
+                };
+                self.click_freeze = Some(ClickFreeze {
+                    anchor,
+                    shadow: (self.target_screen_x, self.target_screen_y),
+                    until,
+                });
             }
-            Click => {
-                // This case handles a full click sequence, often used for actions combined with modifiers.
-                // Press modifiers
-                for &key in &modifier_keys {
-                    enigo.key(key, Press)?;
-                }
+        }
+    }
 
-                // Click action keys
-                for &key in &action_keys {
-                    enigo.key(key, Click)?;
-                }
+    /// Pins the target to the click position until the freeze expires
+    /// or the movement pushes past the stabilisation threshold.
+    fn apply_click_freeze(&mut self) {
+        let now = self.now();
+        let Some(freeze) = &mut self.click_freeze else {
+            return;
+        };
 
-                // Click mouse buttons
-                for &button in &mouse_buttons {
-                    enigo.button(button, Click)?;
-                }
+        let target = (self.target_screen_x, self.target_screen_y);
+        let distance = (((target.0 - freeze.anchor.0) as f32).powi(2)
+            + ((target.1 - freeze.anchor.1) as f32).powi(2))
+        .sqrt();
 
-                // Release modifiers
-                for &key in modifier_keys.iter().rev() {
-                    enigo.key(key, Release)?;
-                }
-            }
+        if now >= freeze.until || distance > self.mouse_config.click_stabilization_threshold {
+            // Released: the target keeps the position movement has reached.
+            self.click_freeze = None;
+            return;
         }
 
-        Ok(())
+        freeze.shadow = target;
+        (self.target_screen_x, self.target_screen_y) = freeze.anchor;
+    }
+
+    /// Adds a relative movement to the target position, carrying sub-pixel remainders between frames.
+    fn apply_relative_movement(&mut self, desired_dx_float: f32, desired_dy_float: f32) {
+        let total_dx_float = desired_dx_float + self.remainder_x;
+        let total_dy_float = desired_dy_float + self.remainder_y;
+
+        let final_dx = total_dx_float.trunc() as i32;
+        let final_dy = total_dy_float.trunc() as i32;
+
+        self.remainder_x = total_dx_float.fract();
+        self.remainder_y = total_dy_float.fract();
+
+        // Apply movement to the target position
+        if final_dx != 0 || final_dy != 0 {
+            let target_x = self.target_screen_x + final_dx;
+            let target_y = self.target_screen_y + final_dy;
+
+            // Relative movement may cross onto any display of the virtual desktop,
+            // unless it is confined to the mapping region.
+            let displays = match self.mapping_region() {
+                Some(region) => vec![region],
+                None => self.display_layout(),
+            };
+            (self.target_screen_x, self.target_screen_y) =
+                display::clamp_to_displays(&displays, target_x, target_y);
+        }
     }
 
     /// Handles mouse movement in air mouse mode.
@@ -392,9 +2111,7 @@ impl MouseMapper {
         is_entering_precision_mode: bool,
     ) {
         // --- Step 1: Transform the raw quaternion to the display coordinate system ---
-        let transformed_quat =
-            nalgebra::Quaternion::new(orientation.w, orientation.j, orientation.i, -orientation.k);
-        let transformed_orientation = UnitQuaternion::new_normalize(transformed_quat);
+        let transformed_orientation = Self::to_display_frame(orientation);
 
         // --- Step 2: Extract Euler angles from the transformed quaternion ---
         let (_roll, pitch, yaw) = transformed_orientation.euler_angles();
@@ -408,14 +2125,17 @@ impl MouseMapper {
             self.precision_mode_center_pitch = vertical_deg;
 
             // Set the current mouse position as the starting point for relative movement.
-            let (x, y) = self.enigo.location().unwrap();
+            // Without a pointer position, continue from where the pointer is heading.
+            let (x, y) = self
+                .pointer_location()
+                .unwrap_or((self.target_screen_x, self.target_screen_y));
             self.precision_mode_start_x = x;
             self.precision_mode_start_y = y;
         }
 
-        let (screen_width, screen_height) = self.enigo.main_display().unwrap();
+        let displays = self.absolute_mapping_area();
 
-        if is_precision_mode_active {
+        let (target_x, target_y) = if is_precision_mode_active {
             // --- Precision Mode: Relative movement based on the initial state ---
 
             // 1. Calculate the angular deviation from the center point.
@@ -423,6 +2143,15 @@ impl MouseMapper {
             let delta_pitch = vertical_deg - self.precision_mode_center_pitch;
 
             // 2. Define sensitivity for precision mode. A larger FOV means slower movement.
+            //    Scale against the display precision mode started on.
+            let start_display = display::display_index_at(
+                &displays,
+                self.precision_mode_start_x,
+                self.precision_mode_start_y,
+            )
+            .map_or(displays[0], |index| displays[index]);
+            let (screen_width, screen_height) = (start_display.width, start_display.height);
+
             const PRECISION_MODE_SENSITIVITY_FACTOR: f32 = 10.0;
             let effective_fov = self.mouse_config.air_mouse_fov * PRECISION_MODE_SENSITIVITY_FACTOR;
             let aspect_ratio = screen_height as f32 / screen_width as f32;
@@ -433,24 +2162,150 @@ impl MouseMapper {
             let offset_y = (-delta_pitch / vertical_fov) * screen_height as f32;
 
             // 4. Calculate the final target position: start point + offset.
-            let target_x = self.precision_mode_start_x + offset_x.round() as i32;
-            let target_y = self.precision_mode_start_y + offset_y.round() as i32;
-
-            self.target_screen_x = target_x.clamp(0, screen_width as i32 - 1);
-            self.target_screen_y = target_y.clamp(0, screen_height as i32 - 1);
+            (
+                self.precision_mode_start_x + offset_x.round() as i32,
+                self.precision_mode_start_y + offset_y.round() as i32,
+            )
         } else {
             // --- Normal Mode: Absolute position mapping ---
-            let x_ratio = (horizontal_deg / self.mouse_config.air_mouse_fov) + 0.5;
-            let aspect_ratio = screen_height as f32 / screen_width as f32;
-            let vertical_fov = self.mouse_config.air_mouse_fov * aspect_ratio;
-            let y_ratio = (-vertical_deg / vertical_fov) + 0.5;
+            // The mapping spans the mapping region, or the bounding box of all selected displays.
+            let area = DisplayRect::bounding(&displays).unwrap();
+
+            let (x_ratio, y_ratio) = match &self.mouse_config.screen_calibration {
+                // A fitted calibration replaces the FOV model.
+                Some(calibration) => {
+                    let direction = transformed_orientation * Vector3::x();
+                    match screen_calibration::map_direction(calibration, &direction) {
+                        Some((u, v)) => (u as f32, v as f32),
+                        // Pointing away from the screen: leave the target where it is.
+                        None => return,
+                    }
+                }
+                None => {
+                    let x_ratio = (horizontal_deg / self.mouse_config.air_mouse_fov) + 0.5;
+                    let aspect_ratio = area.height as f32 / area.width as f32;
+                    let vertical_fov = self.mouse_config.air_mouse_fov * aspect_ratio;
+                    let y_ratio = (-vertical_deg / vertical_fov) + 0.5;
+                    (x_ratio, y_ratio)
+                }
+            };
+
+            (
+                area.x + (x_ratio * area.width as f32).round() as i32,
+                area.y + (y_ratio * area.height as f32).round() as i32,
+            )
+        };
+
+        // Keep the pointer inside the mapping area, never in gaps between displays.
+        (self.target_screen_x, self.target_screen_y) =
+            display::clamp_to_displays(&displays, target_x, target_y);
+    }
+
+    /// Records the handwriting stroke while the touchpad is touched and recognizes it on lift.
+    /// The recognized binding is typed through the same path as button bindings.
+    fn handle_handwriting(&mut self, touchpad: &TouchpadState) {
+        if touchpad.touched {
+            self.handwriting_stroke.push((touchpad.x, touchpad.y));
+            return;
+        }
+        if self.handwriting_stroke.is_empty() {
+            return;
+        }
 
-            let target_x = (x_ratio * screen_width as f32).round() as i32;
-            let target_y = (y_ratio * screen_height as f32).round() as i32;
+        let stroke = std::mem::take(&mut self.handwriting_stroke);
+        let config = &self.mouse_config.handwriting;
+        if unistroke::path_length(&stroke) < config.min_stroke_length {
+            return;
+        }
+
+        let recognition = UnistrokeRecognizer::new(&config.templates).recognize(&stroke);
+        let score = recognition.as_ref().map_or(0.0, |r| r.score);
+        let output = recognition.map(|r| r.output);
+        let typed = output.is_some() && score >= config.min_score;
+
+        if typed {
+            if let Some(key) = &output {
+                self.press_binding(key);
+                self.release_binding(key);
+            }
+        }
+        self.emit_event(
+            "handwriting-recognized",
+            HandwritingResult {
+                output,
+                score,
+                typed,
+            },
+        );
+    }
+
+    /// Presses and releases keys from the controller's roll and pitch in tilt keys mode.
+    /// Tilt is measured from the orientation captured on entering the mode.
+    fn handle_tilt_keys(&mut self, orientation: &UnitQuaternion<f64>) {
+        let (roll, pitch, _yaw) = Self::to_display_frame(orientation).euler_angles();
+        let (roll, pitch) = (roll.to_degrees() as f32, pitch.to_degrees() as f32);
+        let now = self.now();
+
+        let (center_roll, center_pitch) = *self.tilt_keys_center.get_or_insert_with(|| {
+            self.tilt_keys_started = now;
+            (roll, pitch)
+        });
+        let roll = wrap_degrees(roll - center_roll);
+        let pitch = wrap_degrees(pitch - center_pitch);
+
+        let config = self.mouse_config.tilt_keys.clone();
+        let period = Duration::from_millis(config.pulse_period_ms.max(1));
+        let phase = (now.duration_since(self.tilt_keys_started).as_secs_f32()
+            % period.as_secs_f32())
+            / period.as_secs_f32();
+
+        for direction in TiltDirection::ALL {
+            let tilt = match direction {
+                TiltDirection::Left => -roll,
+                TiltDirection::Right => roll,
+                TiltDirection::Forward => -pitch,
+                TiltDirection::Backward => pitch,
+            };
+
+            let index = direction.index();
+            let engaged = if self.tilt_key_states[index].engaged {
+                tilt > config.release_angle
+            } else {
+                tilt >= config.press_angle
+            };
+            self.tilt_key_states[index].engaged = engaged;
+
+            // Partial tilt holds the key for a share of each pulse period, reaching 100% at the full angle.
+            let should_hold = engaged
+                && (!config.pulse || {
+                    let span = (config.full_angle - config.release_angle).max(f32::EPSILON);
+                    let duty = ((tilt - config.release_angle) / span).clamp(0.0, 1.0);
+                    duty >= 1.0 || phase < duty
+                });
+
+            if should_hold && self.tilt_key_states[index].held.is_none() {
+                if let Some(key) = self.keymap_config.tilt_keys.binding(direction) {
+                    let key = key.to_string();
+                    self.press_binding(&key);
+                    self.tilt_key_states[index].held = Some(key);
+                }
+            } else if !should_hold {
+                if let Some(key) = self.tilt_key_states[index].held.take() {
+                    self.release_binding(&key);
+                }
+            }
+        }
+    }
 
-            self.target_screen_x = target_x.clamp(0, screen_width as i32 - 1);
-            self.target_screen_y = target_y.clamp(0, screen_height as i32 - 1);
+    /// Releases the keys held by tilt keys mode and recentres it for the next time it is entered.
+    fn release_tilt_keys(&mut self) {
+        for index in 0..self.tilt_key_states.len() {
+            if let Some(key) = self.tilt_key_states[index].held.take() {
+                self.release_binding(&key);
+            }
+            self.tilt_key_states[index].engaged = false;
         }
+        self.tilt_keys_center = None;
     }
 
     /// Handles mouse movement from the touchpad with relative tracking and acceleration.
@@ -461,6 +2316,11 @@ impl MouseMapper {
         last_touchpad: &TouchpadState,
         delta_t: f32,
     ) {
+        if self.mouse_config.touchpad_absolute {
+            self.handle_absolute_touchpad_movement(current_touchpad);
+            return;
+        }
+
         // Only calculate movement if the finger is touched now and was also touched last frame.
         if current_touchpad.touched && last_touchpad.touched {
             let delta_x = current_touchpad.x - last_touchpad.x;
@@ -471,35 +2331,141 @@ impl MouseMapper {
             }
 
             // Acceleration logic
-            let speed_sq = (delta_x.powi(2) + delta_y.powi(2)) / delta_t;
-            let effective_speed_sq =
-                (speed_sq - self.mouse_config.touchpad_acceleration_threshold).max(0.0);
-            let acceleration_multiplier =
-                1.0 + (effective_speed_sq * 500.0 * self.mouse_config.touchpad_acceleration);
+            let acceleration_multiplier = match &self.mouse_config.touchpad_curve {
+                Some(curve) => {
+                    // delta_t is in milliseconds; curves take pad widths per second.
+                    let speed = (delta_x.powi(2) + delta_y.powi(2)).sqrt() / delta_t * 1000.0;
+                    curve.gain(speed)
+                }
+                None => {
+                    let speed_sq = (delta_x.powi(2) + delta_y.powi(2)) / delta_t;
+                    let effective_speed_sq =
+                        (speed_sq - self.mouse_config.touchpad_acceleration_threshold).max(0.0);
+                    1.0 + (effective_speed_sq * 500.0 * self.mouse_config.touchpad_acceleration)
+                }
+            };
             let base_dx = delta_x * self.mouse_config.touchpad_sensitivity;
             let base_dy = delta_y * self.mouse_config.touchpad_sensitivity;
 
             // Sub-pixel movement logic
-            let desired_dx_float = base_dx * acceleration_multiplier;
-            let desired_dy_float = base_dy * acceleration_multiplier;
+            self.apply_relative_movement(
+                base_dx * acceleration_multiplier,
+                base_dy * acceleration_multiplier,
+            );
+        }
+    }
+
+    /// Maps the touched pad position directly onto the mapping area, like a graphics tablet.
+    fn handle_absolute_touchpad_movement(&mut self, touchpad: &TouchpadState) {
+        if !touchpad.touched {
+            return;
+        }
+
+        let displays = self.absolute_mapping_area();
+        let area = DisplayRect::bounding(&displays).unwrap();
+        let target_x = area.x + (touchpad.x * (area.width - 1) as f32).round() as i32;
+        let target_y = area.y + (touchpad.y * (area.height - 1) as f32).round() as i32;
+
+        (self.target_screen_x, self.target_screen_y) =
+            display::clamp_to_displays(&displays, target_x, target_y);
+    }
 
-            let total_dx_float = desired_dx_float + self.remainder_x;
-            let total_dy_float = desired_dy_float + self.remainder_y;
+    /// Times how long the pointer has rested and fires the dwell click once it has rested long enough.
+    fn dwell_tick(&mut self) {
+        if !self.mouse_config.dwell_click_enabled {
+            self.dwell = None;
+            return;
+        }
+
+        let Some(position) = self.pointer_location() else {
+            return;
+        };
+        let now = self.now();
+        let radius = self.mouse_config.dwell_click_radius;
+
+        let Some(dwell) = &mut self.dwell else {
+            // Start disarmed, so a pointer that has not moved yet never clicks.
+            self.dwell = Some(DwellState {
+                anchor: position,
+                since: now,
+                fired: true,
+                reported_step: 0,
+            });
+            return;
+        };
 
-            let final_dx = total_dx_float.trunc() as i32;
-            let final_dy = total_dy_float.trunc() as i32;
+        let distance = (((position.0 - dwell.anchor.0) as f32).powi(2)
+            + ((position.1 - dwell.anchor.1) as f32).powi(2))
+        .sqrt();
+        if distance > radius {
+            let was_counting = !dwell.fired && dwell.reported_step > 0;
+            *dwell = DwellState {
+                anchor: position,
+                since: now,
+                fired: false,
+                reported_step: 0,
+            };
+            if was_counting {
+                self.report_dwell_progress(position, 0.0);
+            }
+            return;
+        }
+        if dwell.fired {
+            return;
+        }
 
-            self.remainder_x = total_dx_float.fract();
-            self.remainder_y = total_dy_float.fract();
+        let dwell_time = Duration::from_millis(self.mouse_config.dwell_click_time_ms.max(1));
+        let progress =
+            (now.duration_since(dwell.since).as_secs_f32() / dwell_time.as_secs_f32()).min(1.0);
+        let step = (progress * DWELL_PROGRESS_STEPS as f32) as u32;
+        let anchor = dwell.anchor;
+        if step > dwell.reported_step {
+            dwell.reported_step = step;
+            dwell.fired = progress >= 1.0;
+            self.report_dwell_progress(anchor, progress);
+            if progress >= 1.0 {
+                self.perform_dwell_click();
+            }
+        }
+    }
 
-            // Apply movement to the target position
-            if final_dx != 0 || final_dy != 0 {
-                let target_x = self.target_screen_x + final_dx;
-                let target_y = self.target_screen_y + final_dy;
+    /// Sends the dwell countdown to the frontend.
+    fn report_dwell_progress(&self, (x, y): (i32, i32), progress: f32) {
+        self.emit_event(
+            "dwell-progress",
+            DwellProgress {
+                x,
+                y,
+                progress,
+                kind: self.dwell_click_kind,
+            },
+        );
+    }
 
-                let (screen_width, screen_height) = self.enigo.main_display().unwrap();
-                self.target_screen_x = target_x.clamp(0, screen_width as i32 - 1);
-                self.target_screen_y = target_y.clamp(0, screen_height as i32 - 1);
+    /// Performs the currently selected dwell click.
+    fn perform_dwell_click(&mut self) {
+        match self.dwell_click_kind {
+            DwellClickKind::Left => {
+                self.press_binding("Left");
+                self.release_binding("Left");
+            }
+            DwellClickKind::Right => {
+                self.press_binding("Right");
+                self.release_binding("Right");
+            }
+            DwellClickKind::Double => {
+                for _ in 0..2 {
+                    self.press_binding("Left");
+                    self.release_binding("Left");
+                }
+            }
+            DwellClickKind::Drag => {
+                if self.dwell_dragging {
+                    self.release_binding("Left");
+                } else {
+                    self.press_binding("Left");
+                }
+                self.dwell_dragging = !self.dwell_dragging;
             }
         }
     }
@@ -507,9 +2473,20 @@ impl MouseMapper {
     /// Performs one step of interpolation towards the target position.
     /// This should be called at a high, fixed frequency.
     pub fn interpolate_tick(&mut self) {
+        if self.is_paused()
+            || self.is_gamepad_output()
+            || self.mouse_config.mode == MouseMode::GyroAim
+        {
+            return;
+        }
+
+        self.dwell_tick();
+
         // If no input is active, sync the target position with the actual mouse position.
         if !self.is_precision_mode_active && !self.is_air_mouse_active {
-            let (current_x, current_y) = self.enigo.location().unwrap();
+            let Some((current_x, current_y)) = self.pointer_location() else {
+                return;
+            };
             self.target_screen_x = current_x;
             self.target_screen_y = current_y;
 
@@ -517,17 +2494,52 @@ impl MouseMapper {
             self.remainder_x = 0.0;
             self.remainder_y = 0.0;
 
+            // Restart smoothing from the resting pointer position on the next movement.
+            self.pointer_filter.reset();
+            self.last_interpolate_at = None;
+
+            return;
+        }
+
+        let Some((current_x, current_y)) = self.pointer_location() else {
+            return;
+        };
+
+        if self.mouse_config.adaptive_smoothing {
+            let now = self.now();
+            let Some(last_tick) = self.last_interpolate_at.replace(now) else {
+                // Seed the filter with the current pointer position so it starts from rest.
+                self.pointer_filter.reset();
+                self.pointer_filter
+                    .filter(current_x as f32, current_y as f32, 0.0, 0.0, 0.0);
+                return;
+            };
+
+            let (filtered_x, filtered_y) = self.pointer_filter.filter(
+                self.target_screen_x as f32,
+                self.target_screen_y as f32,
+                now.duration_since(last_tick).as_secs_f32(),
+                self.mouse_config.smoothing_min_cutoff,
+                self.mouse_config.smoothing_beta,
+            );
+            let new_x = filtered_x.round() as i32;
+            let new_y = filtered_y.round() as i32;
+
+            if new_x != current_x || new_y != current_y {
+                if let Err(e) = self.backend.move_mouse(new_x, new_y, Coordinate::Abs) {
+                    eprintln!("Failed to move mouse to target position: {:?}", e);
+                }
+            }
             return;
         }
 
-        let (current_x, current_y) = self.enigo.location().unwrap();
         let dx = self.target_screen_x - current_x;
         let dy = self.target_screen_y - current_y;
 
         // If the distance is negligible, snap to the target to prevent jitter.
         if dx.abs() < 1 && dy.abs() < 1 {
             if current_x != self.target_screen_x || current_y != self.target_screen_y {
-                if let Err(e) = self.enigo.move_mouse(
+                if let Err(e) = self.backend.move_mouse(
                     self.target_screen_x,
                     self.target_screen_y,
                     Coordinate::Abs,
@@ -543,8 +2555,574 @@ impl MouseMapper {
         let new_x = current_x + (dx as f32 * SMOOTHING_FACTOR) as i32;
         let new_y = current_y + (dy as f32 * SMOOTHING_FACTOR) as i32;
 
-        if let Err(e) = self.enigo.move_mouse(new_x, new_y, Coordinate::Abs) {
+        if let Err(e) = self.backend.move_mouse(new_x, new_y, Coordinate::Abs) {
             eprintln!("Failed to move mouse to target position: {:?}", e);
         }
     }
 }
+
+impl Drop for MouseMapper {
+    /// Emergency release path: runs when the mapper thread exits, including while unwinding
+    /// from a panic, so held keys and buttons are not left pressed on the host.
+    fn drop(&mut self) {
+        if !self.held_keys.is_empty() {
+            eprintln!(
+                "MouseMapper dropped with {} held key(s), releasing them.",
+                self.held_keys.len()
+            );
+            self.release_all();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mapping::recording_backend::{RecordedInput, RecordingBackend};
+    use std::sync::Mutex;
+
+    /// Builds a mapper on a recording backend with a single 1920x1080 display.
+    fn recording_mapper(
+        mouse_config: MouseConfig,
+        keymap_config: KeymapConfig,
+    ) -> (MouseMapper, Arc<Mutex<Vec<RecordedInput>>>) {
+        let backend = RecordingBackend::new(vec![DisplayRect::new(0, 0, 1920, 1080)]);
+        let recorded = backend.recorded();
+        let (event_tx, _event_rx) = mpsc::unbounded_channel();
+        let mapper = MouseMapper::with_backend(
+            mouse_config,
+            keymap_config,
+            Arc::new(AtomicBool::new(false)),
+            event_tx,
+            Box::new(move |_| Box::new(backend.clone())),
+        );
+        (mapper, recorded)
+    }
+
+    fn controller_state(timestamp: u64, buttons: ButtonState) -> ControllerState {
+        ControllerState {
+            timestamp,
+            buttons,
+            touchpad: TouchpadState {
+                touched: false,
+                x: 0.0,
+                y: 0.0,
+            },
+            orientation: UnitQuaternion::identity(),
+            accelerometer: Vector3::zeros(),
+            gyroscope: Vector3::zeros(),
+            magnetometer: Vector3::zeros(),
+            temperature: 0.0,
+        }
+    }
+
+    /// Feeds trigger states to the mapper, one frame every 10 ms.
+    fn press_trigger(mapper: &mut MouseMapper, frames: &[bool]) {
+        for &trigger in frames {
+            let timestamp = mapper
+                .last_state
+                .as_ref()
+                .map_or(0, |last| last.timestamp + 10);
+            let buttons = ButtonState {
+                trigger,
+                ..Default::default()
+            };
+            mapper.update(&controller_state(timestamp, buttons));
+        }
+    }
+
+    fn left_button(direction: Direction) -> RecordedInput {
+        RecordedInput::Button {
+            button: Button::Left,
+            direction,
+        }
+    }
+
+    fn keymap_with_trigger_behavior(behavior: ButtonBehavior) -> KeymapConfig {
+        let mut keymap_config = KeymapConfig::default();
+        keymap_config.behaviors.trigger = behavior;
+        keymap_config
+    }
+
+    fn button_inputs(recorded: &Mutex<Vec<RecordedInput>>) -> Vec<RecordedInput> {
+        recorded
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|input| matches!(input, RecordedInput::Button { .. }))
+            .cloned()
+            .collect()
+    }
+
+    /// Drives a mapper the way the mapper thread does, on a manual clock.
+    /// The clock and the controller timestamps advance together, one loop tick at a time.
+    struct Rig {
+        mapper: MouseMapper,
+        recorded: Arc<Mutex<Vec<RecordedInput>>>,
+        events: mpsc::UnboundedReceiver<MouseMapperEvent>,
+        clock: Arc<Mutex<Instant>>,
+        state: ControllerState,
+    }
+
+    impl Rig {
+        /// Length of one tick of the mapper loop, in milliseconds.
+        const TICK_MS: u64 = 4;
+
+        fn new(mouse_config: MouseConfig, keymap_config: KeymapConfig) -> Self {
+            let backend = RecordingBackend::new(vec![DisplayRect::new(0, 0, 1920, 1080)]);
+            let recorded = backend.recorded();
+            let (event_tx, events) = mpsc::unbounded_channel();
+            let mut mapper = MouseMapper::with_backend(
+                mouse_config,
+                keymap_config,
+                Arc::new(AtomicBool::new(false)),
+                event_tx,
+                Box::new(move |_| Box::new(backend.clone())),
+            );
+            let clock = Arc::new(Mutex::new(Instant::now()));
+            let mapper_clock = clock.clone();
+            mapper.clock = Box::new(move || *mapper_clock.lock().unwrap());
+
+            let state = controller_state(0, ButtonState::default());
+            mapper.update(&state);
+            Self {
+                mapper,
+                recorded,
+                events,
+                clock,
+                state,
+            }
+        }
+
+        /// Runs the mapper loop for `ms` milliseconds with the current controller state.
+        fn run(&mut self, ms: u64) {
+            for _ in 0..ms.div_ceil(Self::TICK_MS) {
+                *self.clock.lock().unwrap() += Duration::from_millis(Self::TICK_MS);
+                self.state.timestamp += Self::TICK_MS;
+                self.mapper.update(&self.state);
+                self.mapper.binding_tick();
+                self.mapper.interpolate_tick();
+            }
+        }
+
+        fn set_button(&mut self, button: ControllerButton, pressed: bool) {
+            let buttons = &mut self.state.buttons;
+            match button {
+                ControllerButton::Trigger => buttons.trigger = pressed,
+                ControllerButton::Home => buttons.home = pressed,
+                ControllerButton::Back => buttons.back = pressed,
+                ControllerButton::VolumeUp => buttons.volume_up = pressed,
+                ControllerButton::VolumeDown => buttons.volume_down = pressed,
+                ControllerButton::Touchpad => buttons.touchpad = pressed,
+            }
+        }
+
+        /// Presses and releases a button, holding each state for `ms` milliseconds.
+        fn click(&mut self, button: ControllerButton, ms: u64) {
+            self.set_button(button, true);
+            self.run(ms);
+            self.set_button(button, false);
+            self.run(ms);
+        }
+
+        fn touch(&mut self, x: f32, y: f32) {
+            self.state.touchpad = TouchpadState {
+                touched: true,
+                x,
+                y,
+            };
+        }
+
+        fn lift(&mut self) {
+            self.state.touchpad.touched = false;
+        }
+
+        /// Slides the touched finger by `dx` pad widths per tick for `ticks` ticks.
+        fn drag(&mut self, dx: f32, ticks: u32) {
+            for _ in 0..ticks {
+                self.state.touchpad.x += dx;
+                self.run(Self::TICK_MS);
+            }
+        }
+
+        fn pointer(&mut self) -> (i32, i32) {
+            self.mapper.backend.location().unwrap()
+        }
+
+        fn take_events(&mut self) -> Vec<MouseMapperEvent> {
+            std::iter::from_fn(|| self.events.try_recv().ok()).collect()
+        }
+
+        /// Payloads of the frontend events named `name` sent since the last call.
+        fn frontend_events(&mut self, name: &str) -> Vec<serde_json::Value> {
+            self.take_events()
+                .into_iter()
+                .filter_map(|event| match event {
+                    MouseMapperEvent::Frontend { event, payload } if event == name => Some(payload),
+                    _ => None,
+                })
+                .collect()
+        }
+
+        fn key_inputs(&self) -> Vec<RecordedInput> {
+            self.recorded
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|input| matches!(input, RecordedInput::Key { .. }))
+                .cloned()
+                .collect()
+        }
+    }
+
+    /// Mouse config with the plain interpolation, so pointer movement is easy to predict.
+    fn unsmoothed_config() -> MouseConfig {
+        MouseConfig {
+            adaptive_smoothing: false,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn normal_binding_follows_the_button() {
+        let (mut mapper, recorded) =
+            recording_mapper(MouseConfig::default(), KeymapConfig::default());
+
+        press_trigger(&mut mapper, &[false, true]);
+        assert_eq!(button_inputs(&recorded), vec![left_button(Press)]);
+
+        press_trigger(&mut mapper, &[true, false]);
+        assert_eq!(
+            button_inputs(&recorded),
+            vec![left_button(Press), left_button(Release)]
+        );
+    }
+
+    #[test]
+    fn toggle_binding_latches_until_the_next_press() {
+        let (mut mapper, recorded) = recording_mapper(
+            MouseConfig::default(),
+            keymap_with_trigger_behavior(ButtonBehavior::Toggle),
+        );
+
+        press_trigger(&mut mapper, &[false, true, false]);
+        assert_eq!(button_inputs(&recorded), vec![left_button(Press)]);
+
+        press_trigger(&mut mapper, &[false, true, false]);
+        assert_eq!(
+            button_inputs(&recorded),
+            vec![left_button(Press), left_button(Release)]
+        );
+    }
+
+    #[test]
+    fn turbo_binding_clicks_while_held() {
+        let mut rig = Rig::new(
+            MouseConfig::default(),
+            keymap_with_trigger_behavior(ButtonBehavior::Turbo { rate_hz: 100.0 }),
+        );
+
+        // At 100 Hz each half period is 5 ms, so the 4 ms loop flips the button every other tick.
+        rig.set_button(ControllerButton::Trigger, true);
+        rig.run(20);
+        rig.set_button(ControllerButton::Trigger, false);
+        rig.run(40);
+
+        assert_eq!(
+            button_inputs(&rig.recorded),
+            vec![
+                left_button(Press),
+                left_button(Release),
+                left_button(Press),
+                left_button(Release),
+            ]
+        );
+    }
+
+    #[test]
+    fn release_all_releases_held_bindings() {
+        let (mut mapper, recorded) = recording_mapper(
+            MouseConfig::default(),
+            keymap_with_trigger_behavior(ButtonBehavior::Toggle),
+        );
+
+        press_trigger(&mut mapper, &[false, true, false]);
+        mapper.release_all();
+        assert_eq!(
+            button_inputs(&recorded),
+            vec![left_button(Press), left_button(Release)]
+        );
+
+        // The toggle latch is reset too, so the next press holds the button again.
+        press_trigger(&mut mapper, &[false, true]);
+        assert_eq!(
+            button_inputs(&recorded),
+            vec![left_button(Press), left_button(Release), left_button(Press)]
+        );
+    }
+
+    #[test]
+    fn interpolation_eases_towards_the_target() {
+        let mouse_config = MouseConfig {
+            adaptive_smoothing: false,
+            ..Default::default()
+        };
+        let (mut mapper, recorded) = recording_mapper(mouse_config, KeymapConfig::default());
+        mapper.is_precision_mode_active = true;
+        mapper.target_screen_x = 1060;
+        mapper.target_screen_y = 540;
+
+        mapper.interpolate_tick();
+        assert_eq!(
+            recorded.lock().unwrap().last(),
+            Some(&RecordedInput::MoveMouse {
+                x: 990,
+                y: 540,
+                coordinate: Coordinate::Abs,
+            })
+        );
+
+        // Each step covers a fraction of the remaining distance, so it settles just short of the target.
+        for _ in 0..50 {
+            mapper.interpolate_tick();
+        }
+        let (x, y) = mapper.backend.location().unwrap();
+        assert!((1056..=1060).contains(&x), "pointer settled at {x}");
+        assert_eq!(y, 540);
+    }
+
+    #[test]
+    fn resuming_by_command_after_a_chord_pause_keeps_the_next_press() {
+        let keymap_config = KeymapConfig {
+            pause_chord: vec![ControllerButton::Back, ControllerButton::VolumeDown],
+            pause_hold_ms: 100,
+            ..Default::default()
+        };
+        let (mut mapper, recorded) = recording_mapper(MouseConfig::default(), keymap_config);
+        let chord = ButtonState {
+            back: true,
+            volume_down: true,
+            ..Default::default()
+        };
+        let back = ButtonState {
+            back: true,
+            ..Default::default()
+        };
+
+        mapper.update(&controller_state(0, ButtonState::default()));
+        mapper.update(&controller_state(10, chord.clone()));
+        mapper.update(&controller_state(150, chord));
+        assert!(mapper.is_paused());
+        mapper.update(&controller_state(160, ButtonState::default()));
+
+        mapper.set_paused(false);
+        mapper.update(&controller_state(170, back));
+        mapper.update(&controller_state(180, ButtonState::default()));
+
+        let keys: Vec<_> = recorded
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|input| matches!(input, RecordedInput::Key { .. }))
+            .cloned()
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                RecordedInput::Key {
+                    key: Key::Backspace,
+                    direction: Press,
+                },
+                RecordedInput::Key {
+                    key: Key::Backspace,
+                    direction: Release,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn double_click_of_the_gesture_button_cycles_the_mode() {
+        let mut rig = Rig::new(MouseConfig::default(), KeymapConfig::default());
+        assert_eq!(rig.mapper.mouse_config.mode, MouseMode::Touchpad);
+
+        rig.click(ControllerButton::Home, 40);
+        rig.click(ControllerButton::Home, 40);
+
+        assert_eq!(rig.mapper.mouse_config.mode, MouseMode::AirMouse);
+        assert!(
+            rig.take_events()
+                .iter()
+                .any(|event| matches!(event, MouseMapperEvent::ModeChanged(MouseMode::AirMouse)))
+        );
+    }
+
+    #[test]
+    fn holding_the_pause_chord_pauses_without_firing_its_bindings() {
+        let keymap_config = KeymapConfig {
+            pause_chord: vec![ControllerButton::Back, ControllerButton::VolumeDown],
+            pause_hold_ms: 100,
+            ..Default::default()
+        };
+        let mut rig = Rig::new(MouseConfig::default(), keymap_config);
+
+        rig.set_button(ControllerButton::Back, true);
+        rig.set_button(ControllerButton::VolumeDown, true);
+        rig.run(150);
+        assert!(rig.mapper.is_paused());
+        assert_eq!(
+            rig.frontend_events("input-paused"),
+            vec![serde_json::json!(true)]
+        );
+
+        rig.set_button(ControllerButton::Back, false);
+        rig.set_button(ControllerButton::VolumeDown, false);
+        rig.click(ControllerButton::Trigger, 20);
+        assert!(rig.key_inputs().is_empty());
+        assert!(button_inputs(&rig.recorded).is_empty());
+    }
+
+    #[test]
+    fn touchpad_drag_moves_the_pointer() {
+        let mut rig = Rig::new(unsmoothed_config(), KeymapConfig::default());
+
+        rig.touch(0.3, 0.5);
+        rig.run(8);
+        rig.drag(0.01, 10);
+        rig.run(400);
+
+        let (x, y) = rig.pointer();
+        assert!(x > 1000, "pointer moved to {x}");
+        assert_eq!(y, 540);
+    }
+
+    #[test]
+    fn air_mouse_maps_yaw_across_the_field_of_view() {
+        let mouse_config = MouseConfig {
+            mode: MouseMode::AirMouse,
+            ..unsmoothed_config()
+        };
+        let mut rig = Rig::new(mouse_config, KeymapConfig::default());
+
+        // Turn 10 degrees to the right over 100 ms, a quarter of the 40 degree field of view.
+        for step in 1..=25 {
+            let angle = (-10.0 * step as f64 / 25.0).to_radians();
+            rig.state.orientation = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), angle);
+            rig.run(4);
+        }
+
+        assert_eq!(
+            (rig.mapper.target_screen_x, rig.mapper.target_screen_y),
+            (1440, 540)
+        );
+        assert!(rig.pointer().0 > 960);
+    }
+
+    #[test]
+    fn dwell_clicks_once_the_pointer_rests() {
+        let mouse_config = MouseConfig {
+            dwell_click_enabled: true,
+            dwell_click_time_ms: 800,
+            ..unsmoothed_config()
+        };
+        let mut rig = Rig::new(mouse_config, KeymapConfig::default());
+
+        rig.touch(0.3, 0.5);
+        rig.run(8);
+        rig.drag(0.01, 10);
+        rig.lift();
+        rig.take_events();
+
+        rig.run(700);
+        assert!(button_inputs(&rig.recorded).is_empty());
+
+        rig.run(200);
+        assert_eq!(
+            button_inputs(&rig.recorded),
+            vec![left_button(Press), left_button(Release)]
+        );
+        let progress = rig.frontend_events("dwell-progress");
+        assert_eq!(progress.last().unwrap()["progress"], serde_json::json!(1.0));
+
+        // The dwell fires once per rest.
+        rig.run(2000);
+        assert_eq!(button_inputs(&rig.recorded).len(), 2);
+    }
+
+    #[test]
+    fn click_freeze_holds_the_pointer_until_it_expires() {
+        let mouse_config = MouseConfig {
+            click_stabilization_ms: 150,
+            click_stabilization_threshold: 30.0,
+            ..unsmoothed_config()
+        };
+        let mut rig = Rig::new(mouse_config, KeymapConfig::default());
+
+        rig.touch(0.5, 0.5);
+        rig.run(8);
+        rig.set_button(ControllerButton::Trigger, true);
+        rig.run(4);
+
+        // Small movement right after the click stays within the threshold and is held back.
+        rig.drag(0.002, 5);
+        assert_eq!(rig.pointer(), (960, 540));
+
+        // Once the freeze expires, the held-back movement is applied.
+        rig.run(200);
+        assert!(rig.pointer().0 > 960);
+    }
+
+    #[test]
+    fn tilt_keys_press_while_tilted_past_the_press_angle() {
+        let mouse_config = MouseConfig {
+            mode: MouseMode::TiltKeys,
+            ..Default::default()
+        };
+        let mut rig = Rig::new(mouse_config, KeymapConfig::default());
+        rig.run(8);
+
+        // Rolling right past the press angle holds the right tilt key.
+        rig.state.orientation =
+            UnitQuaternion::from_axis_angle(&Vector3::y_axis(), 20f64.to_radians());
+        rig.run(8);
+        let press = RecordedInput::Key {
+            key: Key::Unicode('d'),
+            direction: Press,
+        };
+        assert_eq!(rig.key_inputs(), vec![press.clone()]);
+
+        // Levelling out below the release angle lets go of it.
+        rig.state.orientation = UnitQuaternion::identity();
+        rig.run(8);
+        assert_eq!(
+            rig.key_inputs(),
+            vec![
+                press,
+                RecordedInput::Key {
+                    key: Key::Unicode('d'),
+                    direction: Release,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn scanning_reports_its_highlight_until_it_stops() {
+        let mouse_config = MouseConfig {
+            mode: MouseMode::Scanning,
+            ..Default::default()
+        };
+        let mut rig = Rig::new(mouse_config, KeymapConfig::default());
+
+        rig.run(8);
+        let highlights = rig.frontend_events("scan-highlight");
+        assert_eq!(highlights.len(), 1);
+        assert_eq!(highlights[0]["index"], serde_json::json!(0));
+
+        rig.mapper.set_mode(MouseMode::Touchpad);
+        assert_eq!(
+            rig.frontend_events("scan-highlight"),
+            vec![serde_json::Value::Null]
+        );
+    }
+}
@@ -0,0 +1,131 @@
+//! 1€ filter for pointer smoothing
+//! A low-pass filter whose cutoff rises with speed: slow movements are smoothed heavily to remove
+//! jitter, fast movements lightly to keep lag low. See Casiez et al., "1€ Filter" (CHI 2012).
+
+use std::f32::consts::PI;
+
+/// Cutoff frequency (Hz) used when smoothing the speed estimate itself.
+const DERIVATIVE_CUTOFF: f32 = 1.0;
+
+/// Smoothing factor of a first-order low-pass filter for the given cutoff and time step.
+fn smoothing_factor(cutoff: f32, dt: f32) -> f32 {
+    let tau = 1.0 / (2.0 * PI * cutoff);
+    1.0 / (1.0 + tau / dt)
+}
+
+/// A 2D 1€ filter for screen positions
+#[derive(Debug, Clone, Default)]
+pub struct OneEuroFilter {
+    /// Last filtered position, `None` until the filter is seeded.
+    position: Option<(f32, f32)>,
+    /// Last filtered velocity, in pixels per second.
+    velocity: (f32, f32),
+}
+
+impl OneEuroFilter {
+    /// Creates an empty filter; the first sample passes through unchanged
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forgets the filter state so the next sample starts a new stroke
+    pub fn reset(&mut self) {
+        self.position = None;
+        self.velocity = (0.0, 0.0);
+    }
+
+    /// Filters one sample taken `dt` seconds after the previous one.
+    /// `min_cutoff` (Hz) sets the smoothing at rest, `beta` how quickly it relaxes with speed.
+    pub fn filter(&mut self, x: f32, y: f32, dt: f32, min_cutoff: f32, beta: f32) -> (f32, f32) {
+        let (last_x, last_y) = match self.position {
+            Some(position) if dt > 0.0 => position,
+            _ => {
+                self.position = Some((x, y));
+                return (x, y);
+            }
+        };
+
+        // Smooth the speed estimate, then derive the cutoff for this sample from it.
+        let alpha_d = smoothing_factor(DERIVATIVE_CUTOFF, dt);
+        let raw_velocity = ((x - last_x) / dt, (y - last_y) / dt);
+        self.velocity = (
+            self.velocity.0 + alpha_d * (raw_velocity.0 - self.velocity.0),
+            self.velocity.1 + alpha_d * (raw_velocity.1 - self.velocity.1),
+        );
+        let speed = (self.velocity.0.powi(2) + self.velocity.1.powi(2)).sqrt();
+
+        let cutoff = min_cutoff.max(f32::EPSILON) + beta.max(0.0) * speed;
+        let alpha = smoothing_factor(cutoff, dt);
+        let filtered = (last_x + alpha * (x - last_x), last_y + alpha * (y - last_y));
+
+        self.position = Some(filtered);
+        filtered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DT: f32 = 0.004;
+    const MIN_CUTOFF: f32 = 2.0;
+    const BETA: f32 = 0.01;
+
+    /// Follows a ramp at `speed` px/s for a second and returns how far behind it ends up, in seconds.
+    fn ramp_lag(speed: f32, beta: f32) -> f32 {
+        let mut filter = OneEuroFilter::new();
+        let mut x = 0.0;
+        let mut filtered = filter.filter(x, 0.0, DT, MIN_CUTOFF, beta).0;
+        for _ in 0..250 {
+            x += speed * DT;
+            filtered = filter.filter(x, 0.0, DT, MIN_CUTOFF, beta).0;
+        }
+        (x - filtered) / speed
+    }
+
+    #[test]
+    fn first_sample_passes_through() {
+        let mut filter = OneEuroFilter::new();
+        assert_eq!(
+            filter.filter(12.0, 34.0, DT, MIN_CUTOFF, BETA),
+            (12.0, 34.0)
+        );
+    }
+
+    #[test]
+    fn settles_on_a_steady_input() {
+        let mut filter = OneEuroFilter::new();
+        filter.filter(0.0, 0.0, DT, MIN_CUTOFF, BETA);
+
+        let mut filtered = (0.0, 0.0);
+        for _ in 0..500 {
+            filtered = filter.filter(100.0, -50.0, DT, MIN_CUTOFF, BETA);
+        }
+        assert!((filtered.0 - 100.0).abs() < 0.01, "{filtered:?}");
+        assert!((filtered.1 + 50.0).abs() < 0.01, "{filtered:?}");
+    }
+
+    #[test]
+    fn cutoff_rises_with_speed() {
+        // Without the speed term the filter is linear, so the lag does not depend on speed.
+        assert!((ramp_lag(10.0, 0.0) - ramp_lag(1000.0, 0.0)).abs() < 1e-3);
+
+        // With it, fast movement is smoothed less and trails by a much shorter time.
+        let slow = ramp_lag(10.0, BETA);
+        let fast = ramp_lag(1000.0, BETA);
+        assert!(fast < slow / 2.0, "slow {slow}s, fast {fast}s");
+    }
+
+    #[test]
+    fn reset_starts_a_new_stroke() {
+        let mut filter = OneEuroFilter::new();
+        filter.filter(0.0, 0.0, DT, MIN_CUTOFF, BETA);
+        filter.filter(50.0, 0.0, DT, MIN_CUTOFF, BETA);
+
+        filter.reset();
+        assert_eq!(
+            filter.filter(500.0, 500.0, DT, MIN_CUTOFF, BETA),
+            (500.0, 500.0)
+        );
+    }
+}
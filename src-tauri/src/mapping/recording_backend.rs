@@ -0,0 +1,100 @@
+//! Recording input backend for tests
+//! Records what would have been injected, with a simulated pointer and display layout.
+
+use anyhow::Result;
+use enigo::{Axis, Button, Coordinate, Direction, Key};
+use std::sync::{Arc, Mutex};
+
+use crate::mapping::display::{self, DisplayRect};
+use crate::mapping::input_backend::{InputBackend, MainThreadJob};
+
+/// An input action captured by `RecordingBackend`
+#[derive(Debug, Clone, PartialEq)]
+pub enum RecordedInput {
+    MoveMouse {
+        x: i32,
+        y: i32,
+        coordinate: Coordinate,
+    },
+    Button {
+        button: Button,
+        direction: Direction,
+    },
+    Key {
+        key: Key,
+        direction: Direction,
+    },
+    Scroll {
+        length: i32,
+        axis: Axis,
+    },
+}
+
+/// Backend that records input instead of injecting it, with a simulated pointer and display layout.
+/// Lets the mapping logic be exercised with scripted controller states and no real display.
+#[derive(Debug, Clone)]
+pub struct RecordingBackend {
+    recorded: Arc<Mutex<Vec<RecordedInput>>>,
+    pointer: (i32, i32),
+    displays: Vec<DisplayRect>,
+}
+
+impl RecordingBackend {
+    /// Creates a recording backend with the pointer at the centre of the first display
+    pub fn new(displays: Vec<DisplayRect>) -> Self {
+        let pointer = displays.first().map_or((0, 0), |display| display.center());
+        Self {
+            recorded: Arc::new(Mutex::new(Vec::new())),
+            pointer,
+            displays,
+        }
+    }
+
+    /// Returns a handle to the recorded input that stays valid after the backend is handed over
+    pub fn recorded(&self) -> Arc<Mutex<Vec<RecordedInput>>> {
+        self.recorded.clone()
+    }
+
+    fn record(&self, input: RecordedInput) {
+        self.recorded.lock().unwrap().push(input);
+    }
+}
+
+impl InputBackend for RecordingBackend {
+    fn move_mouse(&mut self, x: i32, y: i32, coordinate: Coordinate) -> Result<()> {
+        let (target_x, target_y) = match coordinate {
+            Coordinate::Abs => (x, y),
+            Coordinate::Rel => (self.pointer.0 + x, self.pointer.1 + y),
+        };
+        self.pointer = display::clamp_to_displays(&self.displays, target_x, target_y);
+        self.record(RecordedInput::MoveMouse { x, y, coordinate });
+        Ok(())
+    }
+
+    fn button(&mut self, button: Button, direction: Direction) -> Result<()> {
+        self.record(RecordedInput::Button { button, direction });
+        Ok(())
+    }
+
+    fn key(&mut self, key: Key, direction: Direction) -> Result<()> {
+        self.record(RecordedInput::Key { key, direction });
+        Ok(())
+    }
+
+    fn scroll(&mut self, length: i32, axis: Axis) -> Result<()> {
+        self.record(RecordedInput::Scroll { length, axis });
+        Ok(())
+    }
+
+    fn location(&self) -> Result<(i32, i32)> {
+        Ok(self.pointer)
+    }
+
+    fn displays(&mut self) -> Result<Vec<DisplayRect>> {
+        Ok(self.displays.clone())
+    }
+
+    fn run_on_main_thread(&mut self, job: MainThreadJob) -> Result<()> {
+        job(self)
+    }
+}
@@ -0,0 +1,187 @@
+//! Point-at-corners screen calibration for the absolute air mouse
+//! The user points the controller at each screen corner in turn, and a homography is fitted
+//! that maps pointing directions to normalized screen positions.
+
+use nalgebra::{Matrix3, SMatrix, SVector, Vector3};
+
+use crate::config::mouse_config::ScreenCalibration;
+
+/// Translation keys for each wizard step, in the order the corners are sampled.
+const CORNER_STEPS: [&str; 4] = [
+    "settings.calibration.screen.steps.top_left",
+    "settings.calibration.screen.steps.top_right",
+    "settings.calibration.screen.steps.bottom_right",
+    "settings.calibration.screen.steps.bottom_left",
+];
+
+/// Normalized screen positions of the corners, matching `CORNER_STEPS`.
+const CORNER_POSITIONS: [(f64, f64); 4] = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+
+/// Collects one pointing direction per screen corner.
+#[derive(Debug, Clone, Default)]
+pub struct ScreenCalibrationWizard {
+    directions: Vec<Vector3<f64>>,
+}
+
+impl ScreenCalibrationWizard {
+    /// Creates a wizard waiting for the first corner
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the translation key of the corner to point at next, or `None` once all are sampled
+    pub fn current_step(&self) -> Option<&'static str> {
+        CORNER_STEPS.get(self.directions.len()).copied()
+    }
+
+    /// Records the pointing direction for the current corner
+    pub fn add_sample(&mut self, direction: Vector3<f64>) {
+        if self.current_step().is_some() {
+            self.directions.push(direction);
+        }
+    }
+
+    /// Fits the calibration once all corners are sampled.
+    /// Returns `None` if samples are missing or the corners are degenerate.
+    pub fn fit(&self) -> Option<ScreenCalibration> {
+        if self.current_step().is_some() {
+            return None;
+        }
+        let homography = fit_homography(&self.directions)?;
+        Some(ScreenCalibration { homography })
+    }
+}
+
+/// Arranges a pointing direction (x forward, y left, z up) as a homogeneous point.
+/// The forward component goes last so it dominates the projective denominator.
+fn to_homogeneous(direction: &Vector3<f64>) -> Vector3<f64> {
+    Vector3::new(direction.y, direction.z, direction.x)
+}
+
+/// Smallest volume spanned by three unit sampled directions that still counts as non-collinear.
+const MIN_CORNER_SPREAD: f64 = 1e-3;
+
+/// Returns whether any three of the directions lie in one plane through the origin,
+/// i.e. on one line of the screen, which leaves the homography undetermined.
+fn has_collinear_corners(points: &[Vector3<f64>]) -> bool {
+    let units: Vec<_> = points.iter().map(|p| p.normalize()).collect();
+    (0..units.len()).any(|i| {
+        (i + 1..units.len()).any(|j| {
+            (j + 1..units.len()).any(|k| {
+                let volume = Matrix3::from_columns(&[units[i], units[j], units[k]]).determinant();
+                // A zero-length sample gives a NaN volume, which counts as degenerate too.
+                volume.is_nan() || volume.abs() < MIN_CORNER_SPREAD
+            })
+        })
+    })
+}
+
+/// Fits the homography mapping the sampled directions onto the screen corners (DLT with h33 = 1).
+/// Returns `None` for degenerate corners, which would otherwise give a meaningless or non-finite fit.
+fn fit_homography(directions: &[Vector3<f64>]) -> Option<Matrix3<f64>> {
+    let points: Vec<_> = directions.iter().map(to_homogeneous).collect();
+    if points.len() != CORNER_POSITIONS.len() || has_collinear_corners(&points) {
+        return None;
+    }
+
+    let mut a = SMatrix::<f64, 8, 8>::zeros();
+    let mut b = SVector::<f64, 8>::zeros();
+
+    for (i, (p, &(u, v))) in points.iter().zip(CORNER_POSITIONS.iter()).enumerate() {
+        let (x, y, w) = (p.x, p.y, p.z);
+
+        let row = 2 * i;
+        a.row_mut(row)
+            .copy_from_slice(&[x, y, w, 0.0, 0.0, 0.0, -u * x, -u * y]);
+        b[row] = u * w;
+
+        a.row_mut(row + 1)
+            .copy_from_slice(&[0.0, 0.0, 0.0, x, y, w, -v * x, -v * y]);
+        b[row + 1] = v * w;
+    }
+
+    let h = a.lu().solve(&b)?;
+    if h.iter().any(|value| !value.is_finite()) {
+        return None;
+    }
+
+    Some(Matrix3::new(
+        h[0], h[1], h[2], h[3], h[4], h[5], h[6], h[7], 1.0,
+    ))
+}
+
+/// Maps a pointing direction to a normalized screen position using the calibration.
+/// Returns `None` when the controller points away from the calibrated screen plane.
+pub fn map_direction(
+    calibration: &ScreenCalibration,
+    direction: &Vector3<f64>,
+) -> Option<(f64, f64)> {
+    let projected = calibration.homography * to_homogeneous(direction);
+    if projected.z <= f64::EPSILON {
+        return None;
+    }
+    Some((projected.x / projected.z, projected.y / projected.z))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Directions towards the corners of a screen seen off-centre, so the fit is fully projective.
+    fn skewed_screen_corners() -> [Vector3<f64>; 4] {
+        [
+            Vector3::new(1.0, 0.3, 0.2),
+            Vector3::new(1.0, -0.35, 0.25),
+            Vector3::new(1.1, -0.3, -0.15),
+            Vector3::new(0.9, 0.25, -0.2),
+        ]
+    }
+
+    fn calibrate(directions: &[Vector3<f64>]) -> Option<ScreenCalibration> {
+        let mut wizard = ScreenCalibrationWizard::new();
+        for &direction in directions {
+            wizard.add_sample(direction);
+        }
+        wizard.fit()
+    }
+
+    #[test]
+    fn fitted_corners_map_back_onto_the_screen_corners() {
+        let corners = skewed_screen_corners();
+        let calibration = calibrate(&corners).unwrap();
+
+        for (direction, &(u, v)) in corners.iter().zip(CORNER_POSITIONS.iter()) {
+            // Only the direction matters, not the length of the vector.
+            for scale in [1.0, 2.5] {
+                let (x, y) = map_direction(&calibration, &(direction * scale)).unwrap();
+                assert!((x - u).abs() < 1e-9 && (y - v).abs() < 1e-9, "{x}, {y}");
+            }
+        }
+    }
+
+    #[test]
+    fn pointing_backwards_maps_to_nothing() {
+        let calibration = calibrate(&skewed_screen_corners()).unwrap();
+        assert_eq!(
+            map_direction(&calibration, &Vector3::new(-1.0, 0.0, 0.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn missing_corners_do_not_fit() {
+        assert!(calibrate(&skewed_screen_corners()[..3]).is_none());
+    }
+
+    #[test]
+    fn collinear_corners_do_not_fit() {
+        // All four directions lie in the horizontal plane, as if the user only swept sideways.
+        let sweep = [-0.3, -0.1, 0.1, 0.3].map(|y| Vector3::new(1.0, y, 0.0));
+        assert!(calibrate(&sweep).is_none());
+
+        // Three corners on one line are just as degenerate.
+        let mut corners = skewed_screen_corners();
+        corners[1] = (corners[0] + corners[2]) / 2.0;
+        assert!(calibrate(&corners).is_none());
+    }
+}
@@ -0,0 +1,205 @@
+//! Minimal Linux uinput wrapper
+//! Creates virtual input devices through /dev/uinput and writes kernel input events to them.
+//! Event codes follow linux/input-event-codes.h.
+
+use anyhow::{Result, anyhow};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::os::fd::AsRawFd;
+use std::os::unix::fs::OpenOptionsExt;
+
+const UINPUT_PATH: &str = "/dev/uinput";
+
+// --- Event types ---
+pub const EV_SYN: u16 = 0x00;
+pub const EV_KEY: u16 = 0x01;
+pub const EV_REL: u16 = 0x02;
+pub const EV_ABS: u16 = 0x03;
+pub const SYN_REPORT: u16 = 0x00;
+
+// --- Relative axes ---
+pub const REL_X: u16 = 0x00;
+pub const REL_Y: u16 = 0x01;
+pub const REL_HWHEEL: u16 = 0x06;
+pub const REL_WHEEL: u16 = 0x08;
+
+// --- Absolute axes ---
+pub const ABS_X: u16 = 0x00;
+pub const ABS_Y: u16 = 0x01;
+pub const ABS_Z: u16 = 0x02;
+pub const ABS_RX: u16 = 0x03;
+pub const ABS_RY: u16 = 0x04;
+pub const ABS_RZ: u16 = 0x05;
+
+// --- Mouse buttons ---
+pub const BTN_LEFT: u16 = 0x110;
+pub const BTN_RIGHT: u16 = 0x111;
+pub const BTN_MIDDLE: u16 = 0x112;
+pub const BTN_SIDE: u16 = 0x113;
+pub const BTN_EXTRA: u16 = 0x114;
+
+// --- Gamepad buttons ---
+pub const BTN_SOUTH: u16 = 0x130;
+pub const BTN_EAST: u16 = 0x131;
+pub const BTN_SELECT: u16 = 0x13a;
+pub const BTN_START: u16 = 0x13b;
+pub const BTN_MODE: u16 = 0x13c;
+pub const BTN_THUMBL: u16 = 0x13d;
+
+const BUS_VIRTUAL: u16 = 0x06;
+
+// --- ioctl requests, _IO/_IOW('U', nr, size) ---
+const UI_DEV_CREATE: u64 = 0x5501;
+const UI_DEV_DESTROY: u64 = 0x5502;
+const UI_DEV_SETUP: u64 = 0x405c_5503;
+const UI_ABS_SETUP: u64 = 0x401c_5504;
+const UI_SET_EVBIT: u64 = 0x4004_5564;
+const UI_SET_KEYBIT: u64 = 0x4004_5565;
+const UI_SET_RELBIT: u64 = 0x4004_5566;
+const UI_SET_ABSBIT: u64 = 0x4004_5567;
+
+/// An absolute axis exposed by a virtual device
+#[derive(Debug, Clone, Copy)]
+pub struct AbsAxis {
+    pub code: u16,
+    pub minimum: i32,
+    pub maximum: i32,
+    /// Values within this distance of the centre are reported as centred by consumers.
+    pub flat: i32,
+}
+
+/// A virtual input device; destroyed when dropped
+pub struct UinputDevice {
+    file: File,
+}
+
+impl UinputDevice {
+    /// Creates a virtual device with the given keys/buttons, relative axes and absolute axes.
+    /// Fails if /dev/uinput is missing or not writable by the current user.
+    pub fn create(
+        name: &str,
+        product: u16,
+        keys: &[u16],
+        relative_axes: &[u16],
+        absolute_axes: &[AbsAxis],
+    ) -> Result<Self> {
+        let file = OpenOptions::new()
+            .write(true)
+            .custom_flags(libc::O_NONBLOCK)
+            .open(UINPUT_PATH)
+            .map_err(|e| anyhow!("Cannot open {}: {}", UINPUT_PATH, e))?;
+        let device = Self { file };
+
+        if !keys.is_empty() {
+            device.ioctl_int(UI_SET_EVBIT, EV_KEY)?;
+            for &key in keys {
+                device.ioctl_int(UI_SET_KEYBIT, key)?;
+            }
+        }
+        if !relative_axes.is_empty() {
+            device.ioctl_int(UI_SET_EVBIT, EV_REL)?;
+            for &axis in relative_axes {
+                device.ioctl_int(UI_SET_RELBIT, axis)?;
+            }
+        }
+        if !absolute_axes.is_empty() {
+            device.ioctl_int(UI_SET_EVBIT, EV_ABS)?;
+            for axis in absolute_axes {
+                device.ioctl_int(UI_SET_ABSBIT, axis.code)?;
+                // SAFETY: uinput_abs_setup is plain old data; all-zero is a valid value.
+                let mut setup: libc::uinput_abs_setup = unsafe { std::mem::zeroed() };
+                setup.code = axis.code;
+                setup.absinfo.minimum = axis.minimum;
+                setup.absinfo.maximum = axis.maximum;
+                setup.absinfo.flat = axis.flat;
+                device.ioctl_ptr(UI_ABS_SETUP, &setup)?;
+            }
+        }
+
+        // SAFETY: uinput_setup is plain old data; all-zero is a valid value.
+        let mut setup: libc::uinput_setup = unsafe { std::mem::zeroed() };
+        setup.id.bustype = BUS_VIRTUAL;
+        setup.id.vendor = 0x04e8; // Samsung
+        setup.id.product = product;
+        setup.id.version = 1;
+        for (dst, &src) in setup
+            .name
+            .iter_mut()
+            .zip(name.as_bytes().iter().take(libc::UINPUT_MAX_NAME_SIZE - 1))
+        {
+            *dst = src as libc::c_char;
+        }
+        device.ioctl_ptr(UI_DEV_SETUP, &setup)?;
+        device.ioctl_none(UI_DEV_CREATE)?;
+
+        Ok(device)
+    }
+
+    /// Writes a single input event. Call `sync` to make a batch of events visible.
+    pub fn emit(&mut self, event_type: u16, code: u16, value: i32) -> Result<()> {
+        // SAFETY: input_event is plain old data; a zero timestamp lets the kernel fill it in.
+        let mut event: libc::input_event = unsafe { std::mem::zeroed() };
+        event.type_ = event_type;
+        event.code = code;
+        event.value = value;
+
+        // SAFETY: the slice covers exactly the bytes of `event`, which outlives it.
+        let bytes = unsafe {
+            std::slice::from_raw_parts(
+                &event as *const libc::input_event as *const u8,
+                std::mem::size_of::<libc::input_event>(),
+            )
+        };
+        self.file.write_all(bytes)?;
+        Ok(())
+    }
+
+    /// Ends the current batch of events
+    pub fn sync(&mut self) -> Result<()> {
+        self.emit(EV_SYN, SYN_REPORT, 0)
+    }
+
+    fn ioctl_int(&self, request: u64, value: u16) -> Result<()> {
+        // SAFETY: the request takes an int argument by value.
+        let result = unsafe {
+            libc::ioctl(
+                self.file.as_raw_fd(),
+                request as _,
+                libc::c_int::from(value),
+            )
+        };
+        Self::check(result, request)
+    }
+
+    fn ioctl_ptr<T>(&self, request: u64, value: &T) -> Result<()> {
+        // SAFETY: the request reads a `T` through the pointer, which is valid for the call.
+        let result =
+            unsafe { libc::ioctl(self.file.as_raw_fd(), request as _, value as *const T) };
+        Self::check(result, request)
+    }
+
+    fn ioctl_none(&self, request: u64) -> Result<()> {
+        // SAFETY: the request takes no argument.
+        let result = unsafe { libc::ioctl(self.file.as_raw_fd(), request as _) };
+        Self::check(result, request)
+    }
+
+    fn check(result: libc::c_int, request: u64) -> Result<()> {
+        if result < 0 {
+            return Err(anyhow!(
+                "uinput ioctl {:#x} failed: {}",
+                request,
+                std::io::Error::last_os_error()
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for UinputDevice {
+    fn drop(&mut self) {
+        if let Err(e) = self.ioctl_none(UI_DEV_DESTROY) {
+            eprintln!("Failed to destroy uinput device: {:?}", e);
+        }
+    }
+}
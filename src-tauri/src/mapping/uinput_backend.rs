@@ -0,0 +1,334 @@
+//! Linux uinput input backend
+//! Injects input at kernel level through virtual devices, so it works on Wayland sessions and
+//! reaches applications that ignore synthetic X events.
+
+use anyhow::{Result, anyhow};
+use enigo::{Axis, Button, Coordinate, Direction, Key};
+use tauri::AppHandle;
+
+use crate::mapping::display::{self, DisplayRect};
+use crate::mapping::input_backend::{InputBackend, MainThreadJob};
+use crate::mapping::uinput::{
+    ABS_X, ABS_Y, AbsAxis, BTN_EXTRA, BTN_LEFT, BTN_MIDDLE, BTN_RIGHT, BTN_SIDE, EV_ABS, EV_KEY,
+    EV_REL, REL_HWHEEL, REL_WHEEL, REL_X, REL_Y, UinputDevice,
+};
+
+/// Resolution of the absolute pointer axes; positions are scaled onto the desktop.
+const ABS_RESOLUTION: i32 = 65535;
+
+const KEY_ESC: u16 = 1;
+const KEY_MINUS: u16 = 12;
+const KEY_EQUAL: u16 = 13;
+const KEY_BACKSPACE: u16 = 14;
+const KEY_TAB: u16 = 15;
+const KEY_LEFTBRACE: u16 = 26;
+const KEY_RIGHTBRACE: u16 = 27;
+const KEY_ENTER: u16 = 28;
+const KEY_LEFTCTRL: u16 = 29;
+const KEY_SEMICOLON: u16 = 39;
+const KEY_APOSTROPHE: u16 = 40;
+const KEY_GRAVE: u16 = 41;
+const KEY_LEFTSHIFT: u16 = 42;
+const KEY_BACKSLASH: u16 = 43;
+const KEY_COMMA: u16 = 51;
+const KEY_DOT: u16 = 52;
+const KEY_SLASH: u16 = 53;
+const KEY_LEFTALT: u16 = 56;
+const KEY_SPACE: u16 = 57;
+const KEY_CAPSLOCK: u16 = 58;
+const KEY_F1: u16 = 59;
+const KEY_F11: u16 = 87;
+const KEY_F12: u16 = 88;
+const KEY_HOME: u16 = 102;
+const KEY_UP: u16 = 103;
+const KEY_PAGEUP: u16 = 104;
+const KEY_LEFT: u16 = 105;
+const KEY_RIGHT: u16 = 106;
+const KEY_END: u16 = 107;
+const KEY_DOWN: u16 = 108;
+const KEY_PAGEDOWN: u16 = 109;
+const KEY_INSERT: u16 = 110;
+const KEY_DELETE: u16 = 111;
+const KEY_MUTE: u16 = 113;
+const KEY_VOLUMEDOWN: u16 = 114;
+const KEY_VOLUMEUP: u16 = 115;
+const KEY_LEFTMETA: u16 = 125;
+
+/// Key codes of the letter rows, in QWERTY order.
+const LETTER_ROWS: [(&str, u16); 3] = [("qwertyuiop", 16), ("asdfghjkl", 30), ("zxcvbnm", 44)];
+
+/// Characters typed with Shift held on a US layout, and their unshifted counterparts.
+const SHIFTED_SYMBOLS: [(char, char); 21] = [
+    ('~', '`'),
+    ('!', '1'),
+    ('@', '2'),
+    ('#', '3'),
+    ('$', '4'),
+    ('%', '5'),
+    ('^', '6'),
+    ('&', '7'),
+    ('*', '8'),
+    ('(', '9'),
+    (')', '0'),
+    ('_', '-'),
+    ('+', '='),
+    ('{', '['),
+    ('}', ']'),
+    ('|', '\\'),
+    (':', ';'),
+    ('"', '\''),
+    ('<', ','),
+    ('>', '.'),
+    ('?', '/'),
+];
+
+/// Returns the key code of an unshifted character on a US layout.
+fn char_code(c: char) -> Option<u16> {
+    for (row, first_code) in LETTER_ROWS {
+        if let Some(index) = row.find(c) {
+            return Some(first_code + index as u16);
+        }
+    }
+    match c {
+        '1'..='9' => Some(2 + (c as u16 - '1' as u16)),
+        '0' => Some(11),
+        '-' => Some(KEY_MINUS),
+        '=' => Some(KEY_EQUAL),
+        '[' => Some(KEY_LEFTBRACE),
+        ']' => Some(KEY_RIGHTBRACE),
+        ';' => Some(KEY_SEMICOLON),
+        '\'' => Some(KEY_APOSTROPHE),
+        '`' => Some(KEY_GRAVE),
+        '\\' => Some(KEY_BACKSLASH),
+        ',' => Some(KEY_COMMA),
+        '.' => Some(KEY_DOT),
+        '/' => Some(KEY_SLASH),
+        ' ' => Some(KEY_SPACE),
+        '\n' => Some(KEY_ENTER),
+        '\t' => Some(KEY_TAB),
+        _ => None,
+    }
+}
+
+/// Returns the key code for a key and whether Shift must be held to produce it.
+fn key_code(key: &Key) -> Option<(u16, bool)> {
+    let code = match key {
+        Key::Escape => KEY_ESC,
+        Key::Backspace => KEY_BACKSPACE,
+        Key::Tab => KEY_TAB,
+        Key::Return => KEY_ENTER,
+        Key::Space => KEY_SPACE,
+        Key::CapsLock => KEY_CAPSLOCK,
+        Key::Control => KEY_LEFTCTRL,
+        Key::Shift => KEY_LEFTSHIFT,
+        Key::Alt => KEY_LEFTALT,
+        Key::Meta => KEY_LEFTMETA,
+        Key::Home => KEY_HOME,
+        Key::End => KEY_END,
+        Key::PageUp => KEY_PAGEUP,
+        Key::PageDown => KEY_PAGEDOWN,
+        Key::UpArrow => KEY_UP,
+        Key::DownArrow => KEY_DOWN,
+        Key::LeftArrow => KEY_LEFT,
+        Key::RightArrow => KEY_RIGHT,
+        Key::Delete => KEY_DELETE,
+        Key::VolumeMute => KEY_MUTE,
+        Key::VolumeDown => KEY_VOLUMEDOWN,
+        Key::VolumeUp => KEY_VOLUMEUP,
+        Key::F1 => KEY_F1,
+        Key::F2 => KEY_F1 + 1,
+        Key::F3 => KEY_F1 + 2,
+        Key::F4 => KEY_F1 + 3,
+        Key::F5 => KEY_F1 + 4,
+        Key::F6 => KEY_F1 + 5,
+        Key::F7 => KEY_F1 + 6,
+        Key::F8 => KEY_F1 + 7,
+        Key::F9 => KEY_F1 + 8,
+        Key::F10 => KEY_F1 + 9,
+        Key::F11 => KEY_F11,
+        Key::F12 => KEY_F12,
+        Key::Insert => KEY_INSERT,
+        Key::Unicode(c) => {
+            if c.is_ascii_uppercase() {
+                return char_code(c.to_ascii_lowercase()).map(|code| (code, true));
+            }
+            if let Some(&(_, base)) = SHIFTED_SYMBOLS.iter().find(|(shifted, _)| shifted == c) {
+                return char_code(base).map(|code| (code, true));
+            }
+            return char_code(*c).map(|code| (code, false));
+        }
+        _ => return None,
+    };
+    Some((code, false))
+}
+
+/// Every key code the virtual keyboard may send.
+fn keyboard_codes() -> Vec<u16> {
+    let mut codes: Vec<u16> = (KEY_ESC..=KEY_F12).collect();
+    codes.extend(KEY_HOME..=KEY_DELETE);
+    codes.extend([KEY_MUTE, KEY_VOLUMEDOWN, KEY_VOLUMEUP, KEY_LEFTMETA]);
+    codes
+}
+
+/// Injects input through uinput virtual devices.
+/// The pointer position cannot be read back from the kernel, so it is tracked here;
+/// movements of other pointing devices are not seen.
+pub struct UinputBackend {
+    /// Keyboard plus relative mouse.
+    relative: UinputDevice,
+    /// Absolute pointer spanning the whole desktop.
+    absolute: UinputDevice,
+    app_handle: AppHandle,
+    /// Tracked pointer position in desktop coordinates.
+    pointer: (i32, i32),
+    /// Display layout the absolute axes are scaled onto.
+    displays: Vec<DisplayRect>,
+}
+
+impl UinputBackend {
+    /// Creates the virtual devices. Fails if /dev/uinput is not writable.
+    pub fn new(app_handle: AppHandle) -> Result<Self> {
+        let mut keys = keyboard_codes();
+        keys.extend([BTN_LEFT, BTN_RIGHT, BTN_MIDDLE, BTN_SIDE, BTN_EXTRA]);
+        let relative = UinputDevice::create(
+            "GearVR Controller Bridge",
+            0x0001,
+            &keys,
+            &[REL_X, REL_Y, REL_WHEEL, REL_HWHEEL],
+            &[],
+        )?;
+
+        let abs_axis = |code| AbsAxis {
+            code,
+            minimum: 0,
+            maximum: ABS_RESOLUTION,
+            flat: 0,
+        };
+        let absolute = UinputDevice::create(
+            "GearVR Controller Bridge Pointer",
+            0x0002,
+            &[BTN_LEFT, BTN_RIGHT, BTN_MIDDLE],
+            &[],
+            &[abs_axis(ABS_X), abs_axis(ABS_Y)],
+        )?;
+
+        let displays = display::query_displays(&app_handle);
+        let pointer = DisplayRect::bounding(&displays).map_or((0, 0), |desktop| desktop.center());
+
+        Ok(Self {
+            relative,
+            absolute,
+            app_handle,
+            pointer,
+            displays,
+        })
+    }
+
+    fn press_code(&mut self, code: u16, pressed: bool) -> Result<()> {
+        self.relative.emit(EV_KEY, code, pressed as i32)?;
+        self.relative.sync()
+    }
+
+    fn send_code(&mut self, code: u16, direction: Direction) -> Result<()> {
+        match direction {
+            Direction::Press => self.press_code(code, true),
+            Direction::Release => self.press_code(code, false),
+            Direction::Click => {
+                self.press_code(code, true)?;
+                self.press_code(code, false)
+            }
+        }
+    }
+}
+
+impl InputBackend for UinputBackend {
+    fn move_mouse(&mut self, x: i32, y: i32, coordinate: Coordinate) -> Result<()> {
+        match coordinate {
+            Coordinate::Rel => {
+                self.relative.emit(EV_REL, REL_X, x)?;
+                self.relative.emit(EV_REL, REL_Y, y)?;
+                self.relative.sync()?;
+                self.pointer = display::clamp_to_displays(
+                    &self.displays,
+                    self.pointer.0 + x,
+                    self.pointer.1 + y,
+                );
+            }
+            Coordinate::Abs => {
+                let desktop = DisplayRect::bounding(&self.displays)
+                    .ok_or_else(|| anyhow!("No displays to map the absolute pointer onto"))?;
+                let scale = |offset: i32, extent: i32| {
+                    let ratio = offset as f64 / (extent - 1).max(1) as f64;
+                    (ratio.clamp(0.0, 1.0) * ABS_RESOLUTION as f64).round() as i32
+                };
+                self.absolute
+                    .emit(EV_ABS, ABS_X, scale(x - desktop.x, desktop.width))?;
+                self.absolute
+                    .emit(EV_ABS, ABS_Y, scale(y - desktop.y, desktop.height))?;
+                self.absolute.sync()?;
+                self.pointer = (x, y);
+            }
+        }
+        Ok(())
+    }
+
+    fn button(&mut self, button: Button, direction: Direction) -> Result<()> {
+        let code = match button {
+            Button::Left => BTN_LEFT,
+            Button::Right => BTN_RIGHT,
+            Button::Middle => BTN_MIDDLE,
+            Button::Back => BTN_SIDE,
+            Button::Forward => BTN_EXTRA,
+            Button::ScrollUp => return self.scroll(-1, Axis::Vertical),
+            Button::ScrollDown => return self.scroll(1, Axis::Vertical),
+            Button::ScrollLeft => return self.scroll(-1, Axis::Horizontal),
+            Button::ScrollRight => return self.scroll(1, Axis::Horizontal),
+        };
+        self.send_code(code, direction)
+    }
+
+    fn key(&mut self, key: Key, direction: Direction) -> Result<()> {
+        let (code, shifted) =
+            key_code(&key).ok_or_else(|| anyhow!("Key {:?} is not supported by uinput", key))?;
+        if !shifted {
+            return self.send_code(code, direction);
+        }
+
+        // Hold Shift around the key, releasing it only after the key itself.
+        if matches!(direction, Direction::Press | Direction::Click) {
+            self.press_code(KEY_LEFTSHIFT, true)?;
+        }
+        self.send_code(code, direction)?;
+        if matches!(direction, Direction::Release | Direction::Click) {
+            self.press_code(KEY_LEFTSHIFT, false)?;
+        }
+        Ok(())
+    }
+
+    fn scroll(&mut self, length: i32, axis: Axis) -> Result<()> {
+        // Positive lengths scroll down/right; the kernel's wheel axis counts up as positive.
+        match axis {
+            Axis::Vertical => self.relative.emit(EV_REL, REL_WHEEL, -length)?,
+            Axis::Horizontal => self.relative.emit(EV_REL, REL_HWHEEL, length)?,
+        }
+        self.relative.sync()
+    }
+
+    fn location(&self) -> Result<(i32, i32)> {
+        Ok(self.pointer)
+    }
+
+    fn displays(&mut self) -> Result<Vec<DisplayRect>> {
+        let displays = display::query_displays(&self.app_handle);
+        if displays.is_empty() {
+            return Err(anyhow!("No displays reported"));
+        }
+        self.displays = displays.clone();
+        Ok(displays)
+    }
+
+    /// Virtual devices have no thread affinity, so jobs run immediately.
+    fn run_on_main_thread(&mut self, job: MainThreadJob) -> Result<()> {
+        job(self)
+    }
+}
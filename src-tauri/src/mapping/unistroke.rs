@@ -0,0 +1,363 @@
+//! Unistroke handwriting recognition
+//! A $1-style template matcher (Wobbrock et al., UIST 2007) for single touchpad strokes.
+//! Strokes are not rotated before matching, so direction matters: a swipe right and a swipe left
+//! are different characters, as in Graffiti.
+
+use crate::config::mouse_config::UnistrokeTemplate;
+use std::f32::consts::PI;
+
+/// Number of points every stroke is resampled to.
+const SAMPLE_COUNT: usize = 64;
+/// Size of the square strokes are scaled into.
+const SQUARE_SIZE: f32 = 250.0;
+
+/// Best match for a stroke
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recognition {
+    pub output: String,
+    /// Similarity from 0.0 (unrelated) to 1.0 (identical)
+    pub score: f32,
+}
+
+/// Matches strokes against a set of templates
+pub struct UnistrokeRecognizer {
+    templates: Vec<(String, Vec<(f32, f32)>)>,
+}
+
+impl UnistrokeRecognizer {
+    /// Creates a recognizer from the built-in alphabet followed by user templates.
+    /// User templates are matched alongside the built-ins, so they can add characters or
+    /// alternative shapes for existing ones.
+    pub fn new(user_templates: &[UnistrokeTemplate]) -> Self {
+        let templates = default_templates()
+            .iter()
+            .chain(user_templates)
+            .filter(|template| template.points.len() >= 2)
+            .map(|template| {
+                let points: Vec<(f32, f32)> =
+                    template.points.iter().map(|p| (p[0], p[1])).collect();
+                (template.output.clone(), normalize(&points))
+            })
+            .collect();
+        Self { templates }
+    }
+
+    /// Returns the template closest to the stroke, or `None` if the stroke is too short to match.
+    pub fn recognize(&self, stroke: &[(f32, f32)]) -> Option<Recognition> {
+        if stroke.len() < 2 || path_length(stroke) <= f32::EPSILON {
+            return None;
+        }
+        let candidate = normalize(stroke);
+        let half_diagonal = 0.5 * (2.0 * SQUARE_SIZE * SQUARE_SIZE).sqrt();
+
+        self.templates
+            .iter()
+            .map(|(output, points)| (output, path_distance(&candidate, points)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(output, distance)| Recognition {
+                output: output.clone(),
+                score: (1.0 - distance / half_diagonal).max(0.0),
+            })
+    }
+}
+
+/// Resamples, scales and centres a stroke so it can be compared point by point.
+fn normalize(points: &[(f32, f32)]) -> Vec<(f32, f32)> {
+    let points = resample(points, SAMPLE_COUNT);
+
+    // Scale uniformly, so straight lines keep their direction instead of being stretched into a box.
+    let (min_x, max_x, min_y, max_y) = points.iter().fold(
+        (f32::MAX, f32::MIN, f32::MAX, f32::MIN),
+        |(min_x, max_x, min_y, max_y), &(x, y)| {
+            (min_x.min(x), max_x.max(x), min_y.min(y), max_y.max(y))
+        },
+    );
+    let scale = SQUARE_SIZE / (max_x - min_x).max(max_y - min_y).max(f32::EPSILON);
+
+    let count = points.len() as f32;
+    let (centroid_x, centroid_y) = points.iter().fold((0.0, 0.0), |(sx, sy), &(x, y)| {
+        (sx + x / count, sy + y / count)
+    });
+
+    points
+        .iter()
+        .map(|&(x, y)| ((x - centroid_x) * scale, (y - centroid_y) * scale))
+        .collect()
+}
+
+/// Resamples a stroke into `count` points evenly spaced along its path.
+fn resample(points: &[(f32, f32)], count: usize) -> Vec<(f32, f32)> {
+    let interval = path_length(points) / (count - 1) as f32;
+    let mut resampled = vec![points[0]];
+    let mut accumulated = 0.0;
+    let mut previous = points[0];
+
+    for &point in &points[1..] {
+        let mut segment = distance(previous, point);
+        while interval > 0.0 && accumulated + segment >= interval && resampled.len() < count {
+            let t = (interval - accumulated) / segment;
+            let inserted = (
+                previous.0 + t * (point.0 - previous.0),
+                previous.1 + t * (point.1 - previous.1),
+            );
+            resampled.push(inserted);
+            segment -= interval - accumulated;
+            previous = inserted;
+            accumulated = 0.0;
+        }
+        accumulated += segment;
+        previous = point;
+    }
+
+    // Rounding can leave the last point out.
+    while resampled.len() < count {
+        resampled.push(*points.last().unwrap());
+    }
+    resampled
+}
+
+/// Length of a stroke along its path.
+pub fn path_length(points: &[(f32, f32)]) -> f32 {
+    points
+        .windows(2)
+        .map(|pair| distance(pair[0], pair[1]))
+        .sum()
+}
+
+/// Mean distance between corresponding points of two normalized strokes.
+fn path_distance(a: &[(f32, f32)], b: &[(f32, f32)]) -> f32 {
+    let total: f32 = a.iter().zip(b).map(|(&p, &q)| distance(p, q)).sum();
+    total / a.len().min(b.len()).max(1) as f32
+}
+
+fn distance(a: (f32, f32), b: (f32, f32)) -> f32 {
+    ((b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)).sqrt()
+}
+
+/// Points along a circular arc, with angles in degrees counter-clockwise from the right
+/// as seen on the touchpad (y down).
+fn arc(center: (f32, f32), radius: f32, start_deg: f32, end_deg: f32) -> Vec<[f32; 2]> {
+    const STEPS: usize = 16;
+    (0..=STEPS)
+        .map(|i| {
+            let angle = (start_deg + (end_deg - start_deg) * i as f32 / STEPS as f32) * PI / 180.0;
+            [
+                center.0 + radius * angle.cos(),
+                center.1 - radius * angle.sin(),
+            ]
+        })
+        .collect()
+}
+
+/// The built-in Graffiti-like alphabet.
+fn default_templates() -> Vec<UnistrokeTemplate> {
+    let template = |output: &str, points: Vec<[f32; 2]>| UnistrokeTemplate {
+        output: output.to_string(),
+        points,
+    };
+    let line = |points: &[[f32; 2]]| points.to_vec();
+
+    vec![
+        template("a", line(&[[0.0, 1.0], [0.5, 0.0], [1.0, 1.0]])),
+        template(
+            "b",
+            line(&[
+                [0.0, 0.0],
+                [0.0, 1.0],
+                [0.0, 0.0],
+                [0.8, 0.1],
+                [0.8, 0.4],
+                [0.0, 0.5],
+                [0.9, 0.6],
+                [0.9, 0.9],
+                [0.0, 1.0],
+            ]),
+        ),
+        template("c", arc((0.5, 0.5), 0.5, 45.0, 315.0)),
+        template(
+            "d",
+            line(&[
+                [0.0, 0.0],
+                [0.0, 1.0],
+                [0.0, 0.0],
+                [0.7, 0.1],
+                [1.0, 0.5],
+                [0.7, 0.9],
+                [0.0, 1.0],
+            ]),
+        ),
+        template(
+            "e",
+            [
+                arc((0.5, 0.25), 0.25, 30.0, 270.0),
+                arc((0.5, 0.75), 0.25, 90.0, 330.0),
+            ]
+            .concat(),
+        ),
+        template("f", line(&[[1.0, 0.0], [0.0, 0.0], [0.0, 1.0]])),
+        template(
+            "g",
+            [
+                arc((0.5, 0.5), 0.5, 45.0, 315.0),
+                line(&[[0.85, 0.5], [0.5, 0.5]]),
+            ]
+            .concat(),
+        ),
+        template(
+            "h",
+            line(&[[0.0, 0.0], [0.0, 1.0], [0.0, 0.5], [1.0, 0.5], [1.0, 1.0]]),
+        ),
+        template("i", line(&[[0.5, 0.0], [0.5, 1.0]])),
+        template("j", line(&[[1.0, 0.0], [1.0, 0.8], [0.5, 1.0], [0.0, 0.8]])),
+        template("k", line(&[[1.0, 0.0], [0.0, 0.5], [1.0, 1.0]])),
+        template("l", line(&[[0.0, 0.0], [0.0, 1.0], [1.0, 1.0]])),
+        template(
+            "m",
+            line(&[[0.0, 1.0], [0.2, 0.0], [0.5, 0.6], [0.8, 0.0], [1.0, 1.0]]),
+        ),
+        template("n", line(&[[0.0, 1.0], [0.0, 0.0], [1.0, 1.0], [1.0, 0.0]])),
+        template("o", arc((0.5, 0.5), 0.5, 90.0, 450.0)),
+        template(
+            "p",
+            line(&[[0.0, 1.0], [0.0, 0.0], [0.8, 0.1], [0.8, 0.4], [0.0, 0.5]]),
+        ),
+        template(
+            "q",
+            [
+                arc((0.5, 0.3), 0.3, 0.0, 360.0),
+                line(&[[0.8, 1.0], [1.0, 0.9]]),
+            ]
+            .concat(),
+        ),
+        template(
+            "r",
+            line(&[
+                [0.0, 1.0],
+                [0.0, 0.0],
+                [0.8, 0.1],
+                [0.8, 0.4],
+                [0.0, 0.5],
+                [1.0, 1.0],
+            ]),
+        ),
+        template(
+            "s",
+            [
+                arc((0.5, 0.25), 0.25, 30.0, 270.0),
+                arc((0.5, 0.75), 0.25, 90.0, -150.0),
+            ]
+            .concat(),
+        ),
+        template("t", line(&[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]])),
+        template(
+            "u",
+            line(&[[0.0, 0.0], [0.0, 0.8], [0.5, 1.0], [1.0, 0.8], [1.0, 0.0]]),
+        ),
+        template("v", line(&[[0.0, 0.0], [0.5, 1.0], [1.0, 0.0]])),
+        template(
+            "w",
+            line(&[[0.0, 0.0], [0.25, 1.0], [0.5, 0.3], [0.75, 1.0], [1.0, 0.0]]),
+        ),
+        template("x", line(&[[0.0, 0.0], [1.0, 1.0]])),
+        template("y", line(&[[0.0, 0.0], [0.5, 0.5], [1.0, 0.0], [0.2, 1.0]])),
+        template("z", line(&[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0]])),
+        template("Space", line(&[[0.0, 0.5], [1.0, 0.5]])),
+        template("Backspace", line(&[[1.0, 0.5], [0.0, 0.5]])),
+        template("Enter", line(&[[1.0, 0.0], [0.0, 1.0]])),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::mouse_config::HandwritingConfig;
+
+    fn points(template: &UnistrokeTemplate) -> Vec<(f32, f32)> {
+        template.points.iter().map(|p| (p[0], p[1])).collect()
+    }
+
+    /// Redraws a template at another size and position, with extra points and a little wobble.
+    fn redraw(template: &UnistrokeTemplate) -> Vec<(f32, f32)> {
+        let original = points(template);
+        let mut stroke = Vec::new();
+        for pair in original.windows(2) {
+            for step in 0..4 {
+                let t = step as f32 / 4.0;
+                stroke.push((
+                    pair[0].0 + t * (pair[1].0 - pair[0].0),
+                    pair[0].1 + t * (pair[1].1 - pair[0].1),
+                ));
+            }
+        }
+        stroke.push(*original.last().unwrap());
+
+        stroke
+            .iter()
+            .enumerate()
+            .map(|(i, &(x, y))| {
+                let wobble = 0.02 * (i as f32 * 1.7).sin();
+                (0.3 + 0.4 * (x + wobble), -0.2 + 0.4 * (y - wobble))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn every_letter_has_a_template() {
+        let templates = default_templates();
+        for letter in 'a'..='z' {
+            assert!(
+                templates
+                    .iter()
+                    .any(|template| template.output == letter.to_string()),
+                "no template for '{letter}'"
+            );
+        }
+    }
+
+    #[test]
+    fn templates_recognize_themselves() {
+        let recognizer = UnistrokeRecognizer::new(&[]);
+        for template in default_templates() {
+            let recognition = recognizer.recognize(&points(&template)).unwrap();
+            assert_eq!(recognition.output, template.output);
+            assert!(recognition.score > 0.99, "{recognition:?}");
+        }
+    }
+
+    #[test]
+    fn templates_recognize_redrawn_strokes() {
+        let recognizer = UnistrokeRecognizer::new(&[]);
+        for template in default_templates() {
+            let recognition = recognizer.recognize(&redraw(&template)).unwrap();
+            assert_eq!(recognition.output, template.output);
+            assert!(
+                recognition.score >= HandwritingConfig::default().min_score,
+                "{recognition:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn user_templates_add_characters() {
+        let star = UnistrokeTemplate {
+            output: "*".to_string(),
+            points: vec![
+                [0.0, 1.0],
+                [0.5, 0.0],
+                [1.0, 1.0],
+                [0.0, 0.3],
+                [1.0, 0.3],
+                [0.0, 1.0],
+            ],
+        };
+        let recognizer = UnistrokeRecognizer::new(std::slice::from_ref(&star));
+        assert_eq!(recognizer.recognize(&points(&star)).unwrap().output, "*");
+    }
+
+    #[test]
+    fn degenerate_strokes_are_not_recognized() {
+        let recognizer = UnistrokeRecognizer::new(&[]);
+        assert_eq!(recognizer.recognize(&[(0.5, 0.5)]), None);
+        assert_eq!(recognizer.recognize(&[(0.5, 0.5), (0.5, 0.5)]), None);
+    }
+}
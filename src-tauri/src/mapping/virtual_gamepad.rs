@@ -0,0 +1,167 @@
+//! Virtual gamepad output
+//! Presents the controller to games as a uinput joystick with two sticks, two triggers and buttons.
+
+use anyhow::Result;
+
+use crate::config::mouse_config::GamepadConfig;
+use crate::core::controller::{ButtonState, ControllerButton, ControllerState};
+use crate::mapping::uinput::{
+    ABS_RX, ABS_RY, ABS_RZ, ABS_X, ABS_Y, ABS_Z, AbsAxis, BTN_EAST, BTN_MODE, BTN_SELECT,
+    BTN_SOUTH, BTN_START, BTN_THUMBL, EV_ABS, EV_KEY, UinputDevice,
+};
+
+/// Range of the stick axes.
+const STICK_MAX: i32 = 32767;
+/// Range of the trigger axes.
+const TRIGGER_MAX: i32 = 1023;
+
+/// One frame of gamepad output, with sticks in -1.0..=1.0 and triggers in 0.0..=1.0.
+/// Positive stick y points down, following the Linux joystick convention.
+#[derive(Debug, Clone, Default)]
+pub struct GamepadReport {
+    pub left_stick: (f32, f32),
+    pub right_stick: (f32, f32),
+    pub left_trigger: f32,
+    pub right_trigger: f32,
+    pub buttons: ButtonState,
+}
+
+impl GamepadReport {
+    /// Builds a report from the controller state and its display-frame Euler angles in degrees,
+    /// with yaw taken relative to the heading gamepad output started at.
+    /// The touchpad drives the left stick, yaw and pitch the right stick, roll the triggers.
+    pub fn from_state(
+        state: &ControllerState,
+        (roll, pitch, yaw): (f32, f32, f32),
+        config: &GamepadConfig,
+    ) -> Self {
+        let axis = |angle: f32, range: f32| {
+            let magnitude = (angle.abs() - config.deadzone).max(0.0);
+            let span = (range - config.deadzone).max(f32::EPSILON);
+            (magnitude / span).min(1.0) * angle.signum()
+        };
+
+        let left_stick = if state.touchpad.touched {
+            (
+                (state.touchpad.x - 0.5) * 2.0,
+                (state.touchpad.y - 0.5) * 2.0,
+            )
+        } else {
+            (0.0, 0.0)
+        };
+        let right_stick = (
+            axis(yaw, config.yaw_range),
+            -axis(pitch, config.pitch_range),
+        );
+        let (left_trigger, right_trigger) = if config.roll_to_triggers {
+            let tilt = axis(roll, config.roll_range);
+            ((-tilt).max(0.0), tilt.max(0.0))
+        } else {
+            (0.0, 0.0)
+        };
+
+        Self {
+            left_stick,
+            right_stick,
+            left_trigger,
+            right_trigger,
+            buttons: state.buttons.clone(),
+        }
+    }
+}
+
+/// Gamepad button each controller button is reported as.
+fn gamepad_button(button: ControllerButton) -> u16 {
+    match button {
+        ControllerButton::Trigger => BTN_SOUTH,
+        ControllerButton::Back => BTN_EAST,
+        ControllerButton::Home => BTN_MODE,
+        ControllerButton::VolumeUp => BTN_START,
+        ControllerButton::VolumeDown => BTN_SELECT,
+        ControllerButton::Touchpad => BTN_THUMBL,
+    }
+}
+
+/// A uinput joystick; removed from the system when dropped
+pub struct VirtualGamepad {
+    device: UinputDevice,
+    /// Button states last sent, indexed by `ControllerButton::index`.
+    pressed: [bool; ControllerButton::ALL.len()],
+}
+
+impl VirtualGamepad {
+    /// Creates the virtual joystick. Fails if /dev/uinput is not writable.
+    pub fn new() -> Result<Self> {
+        let stick = |code| AbsAxis {
+            code,
+            minimum: -STICK_MAX,
+            maximum: STICK_MAX,
+            flat: 0,
+        };
+        let trigger = |code| AbsAxis {
+            code,
+            minimum: 0,
+            maximum: TRIGGER_MAX,
+            flat: 0,
+        };
+        let buttons: Vec<u16> = ControllerButton::ALL
+            .iter()
+            .map(|&button| gamepad_button(button))
+            .collect();
+
+        let device = UinputDevice::create(
+            "GearVR Controller Gamepad",
+            0x0003,
+            &buttons,
+            &[],
+            &[
+                stick(ABS_X),
+                stick(ABS_Y),
+                stick(ABS_RX),
+                stick(ABS_RY),
+                trigger(ABS_Z),
+                trigger(ABS_RZ),
+            ],
+        )?;
+
+        Ok(Self {
+            device,
+            pressed: [false; ControllerButton::ALL.len()],
+        })
+    }
+
+    /// Sends a frame of gamepad output. Buttons are only reported when they change.
+    pub fn send(&mut self, report: &GamepadReport) -> Result<()> {
+        let stick = |value: f32| (value.clamp(-1.0, 1.0) * STICK_MAX as f32).round() as i32;
+        let trigger = |value: f32| (value.clamp(0.0, 1.0) * TRIGGER_MAX as f32).round() as i32;
+
+        self.device
+            .emit(EV_ABS, ABS_X, stick(report.left_stick.0))?;
+        self.device
+            .emit(EV_ABS, ABS_Y, stick(report.left_stick.1))?;
+        self.device
+            .emit(EV_ABS, ABS_RX, stick(report.right_stick.0))?;
+        self.device
+            .emit(EV_ABS, ABS_RY, stick(report.right_stick.1))?;
+        self.device
+            .emit(EV_ABS, ABS_Z, trigger(report.left_trigger))?;
+        self.device
+            .emit(EV_ABS, ABS_RZ, trigger(report.right_trigger))?;
+
+        for button in ControllerButton::ALL {
+            let is_pressed = report.buttons.is_pressed(button);
+            if self.pressed[button.index()] != is_pressed {
+                self.pressed[button.index()] = is_pressed;
+                self.device
+                    .emit(EV_KEY, gamepad_button(button), is_pressed as i32)?;
+            }
+        }
+
+        self.device.sync()
+    }
+
+    /// Centres the sticks and releases the triggers and every button.
+    pub fn reset(&mut self) -> Result<()> {
+        self.send(&GamepadReport::default())
+    }
+}
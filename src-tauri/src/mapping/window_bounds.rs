@@ -0,0 +1,323 @@
+//! Window bounds lookup for mapping regions that follow a window
+//! Finds a top-level window by title and reports its bounds in mouse coordinates.
+//! Supported on Windows and X11; elsewhere no window is ever found, so the mapping falls back to the displays.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::mapping::display::DisplayRect;
+
+/// How often a tracked window's bounds are looked up again.
+const WINDOW_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Follows the bounds of a window on a background thread, so lookups never stall the mapper loop.
+/// The thread stops once the tracker is dropped.
+pub struct WindowTracker {
+    title: String,
+    bounds: Arc<Mutex<Option<DisplayRect>>>,
+    running: Arc<AtomicBool>,
+}
+
+impl WindowTracker {
+    /// Starts tracking the first visible window whose title contains `title`, ignoring case
+    pub fn new(title: &str) -> Self {
+        let bounds = Arc::new(Mutex::new(None));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let thread_title = title.to_string();
+        let thread_bounds = bounds.clone();
+        let thread_running = running.clone();
+        thread::spawn(move || {
+            let mut finder = WindowFinder::default();
+            while thread_running.load(Ordering::SeqCst) {
+                let found = finder.find(&thread_title);
+                *thread_bounds.lock().unwrap() = found;
+                thread::sleep(WINDOW_POLL_INTERVAL);
+            }
+        });
+
+        Self {
+            title: title.to_string(),
+            bounds,
+            running,
+        }
+    }
+
+    /// The title this tracker searches for
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// The window's bounds from the latest lookup, or `None` if it was not found
+    pub fn bounds(&self) -> Option<DisplayRect> {
+        *self.bounds.lock().unwrap()
+    }
+}
+
+impl Drop for WindowTracker {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Returns whether a window title matches the searched-for title.
+#[cfg(any(target_os = "windows", all(unix, not(target_os = "macos"))))]
+fn title_matches(window_title: &str, title: &str) -> bool {
+    !title.is_empty() && window_title.to_lowercase().contains(&title.to_lowercase())
+}
+
+/// Looks up window bounds, keeping whatever platform connection that needs between lookups.
+#[cfg(target_os = "windows")]
+#[derive(Default)]
+struct WindowFinder;
+
+#[cfg(target_os = "windows")]
+impl WindowFinder {
+    /// Looks up the bounds of the first visible window whose title contains `title`, ignoring case.
+    fn find(&mut self, title: &str) -> Option<DisplayRect> {
+        use windows_sys::Win32::Foundation::{BOOL, HWND, LPARAM, RECT};
+        use windows_sys::Win32::UI::WindowsAndMessaging::{
+            EnumWindows, GetWindowRect, GetWindowTextW, IsIconic, IsWindowVisible,
+        };
+
+        struct Search<'a> {
+            title: &'a str,
+            found: Option<DisplayRect>,
+        }
+
+        unsafe extern "system" fn visit(hwnd: HWND, lparam: LPARAM) -> BOOL {
+            // SAFETY: `lparam` is the `Search` passed to `EnumWindows` below, which outlives the enumeration.
+            let search = unsafe { &mut *(lparam as *mut Search) };
+            if unsafe { IsWindowVisible(hwnd) } == 0 || unsafe { IsIconic(hwnd) } != 0 {
+                return 1;
+            }
+
+            let mut buffer = [0u16; 512];
+            let length = unsafe { GetWindowTextW(hwnd, buffer.as_mut_ptr(), buffer.len() as i32) };
+            if length <= 0
+                || !title_matches(
+                    &String::from_utf16_lossy(&buffer[..length as usize]),
+                    search.title,
+                )
+            {
+                return 1;
+            }
+
+            let mut rect = RECT {
+                left: 0,
+                top: 0,
+                right: 0,
+                bottom: 0,
+            };
+            if unsafe { GetWindowRect(hwnd, &mut rect) } == 0 {
+                return 1;
+            }
+            search.found = Some(DisplayRect::new(
+                rect.left,
+                rect.top,
+                rect.right - rect.left,
+                rect.bottom - rect.top,
+            ));
+            // Stop enumerating.
+            0
+        }
+
+        let mut search = Search { title, found: None };
+        // EnumWindows reports failure when the callback stops early, so its result is not checked.
+        unsafe {
+            EnumWindows(Some(visit), &mut search as *mut Search as LPARAM);
+        }
+        search.found
+    }
+}
+
+/// Looks up window bounds, keeping whatever platform connection that needs between lookups.
+/// On X11 the connection is opened on first use and reopened after it fails.
+#[cfg(all(unix, not(target_os = "macos")))]
+#[derive(Default)]
+struct WindowFinder {
+    session: Option<X11Session>,
+}
+
+/// An X server connection with the atoms window lookup needs.
+#[cfg(all(unix, not(target_os = "macos")))]
+struct X11Session {
+    conn: x11rb::rust_connection::RustConnection,
+    root: x11rb::protocol::xproto::Window,
+    client_list: u32,
+    net_wm_name: u32,
+    net_wm_state: u32,
+    net_wm_state_hidden: u32,
+    utf8_string: u32,
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+impl X11Session {
+    fn connect() -> Option<Self> {
+        use x11rb::connection::Connection;
+        use x11rb::protocol::xproto::ConnectionExt;
+
+        let (conn, screen_num) = x11rb::connect(None).ok()?;
+        let root = conn.setup().roots[screen_num].root;
+        let intern = |name: &[u8]| {
+            conn.intern_atom(false, name)
+                .ok()?
+                .reply()
+                .ok()
+                .map(|reply| reply.atom)
+        };
+        Some(Self {
+            client_list: intern(b"_NET_CLIENT_LIST")?,
+            net_wm_name: intern(b"_NET_WM_NAME")?,
+            net_wm_state: intern(b"_NET_WM_STATE")?,
+            net_wm_state_hidden: intern(b"_NET_WM_STATE_HIDDEN")?,
+            utf8_string: intern(b"UTF8_STRING")?,
+            conn,
+            root,
+        })
+    }
+
+    /// Returns the top-level client windows, or `None` if the connection failed.
+    fn clients(&self) -> Option<Vec<u32>> {
+        use x11rb::protocol::xproto::{AtomEnum, ConnectionExt};
+
+        let reply = self
+            .conn
+            .get_property(
+                false,
+                self.root,
+                self.client_list,
+                AtomEnum::WINDOW,
+                0,
+                u32::MAX,
+            )
+            .ok()?
+            .reply()
+            .ok()?;
+        Some(reply.value32()?.collect())
+    }
+
+    fn property(&self, window: u32, name: u32, kind: u32) -> Option<Vec<u8>> {
+        use x11rb::protocol::xproto::ConnectionExt;
+
+        self.conn
+            .get_property(false, window, name, kind, 0, 1024)
+            .ok()?
+            .reply()
+            .ok()
+            .map(|reply| reply.value)
+            .filter(|value| !value.is_empty())
+    }
+
+    fn title(&self, window: u32) -> Option<String> {
+        use x11rb::protocol::xproto::AtomEnum;
+
+        self.property(window, self.net_wm_name, self.utf8_string)
+            .or_else(|| self.property(window, AtomEnum::WM_NAME.into(), AtomEnum::STRING.into()))
+            .map(|value| String::from_utf8_lossy(&value).into_owned())
+    }
+
+    /// Returns whether the window is mapped on screen and not minimized.
+    /// Windows on other workspaces are unmapped by the window manager, so they count as hidden too.
+    fn is_visible(&self, window: u32) -> bool {
+        use x11rb::protocol::xproto::{AtomEnum, ConnectionExt, MapState};
+
+        let is_viewable = self
+            .conn
+            .get_window_attributes(window)
+            .ok()
+            .and_then(|cookie| cookie.reply().ok())
+            .is_some_and(|attributes| attributes.map_state == MapState::VIEWABLE);
+        let is_hidden = self
+            .property(window, self.net_wm_state, AtomEnum::ATOM.into())
+            .is_some_and(|value| {
+                value.chunks_exact(4).any(|atom| {
+                    u32::from_ne_bytes([atom[0], atom[1], atom[2], atom[3]])
+                        == self.net_wm_state_hidden
+                })
+            });
+        is_viewable && !is_hidden
+    }
+
+    fn bounds(&self, window: u32) -> Option<DisplayRect> {
+        use x11rb::protocol::xproto::ConnectionExt;
+
+        let geometry = self.conn.get_geometry(window).ok()?.reply().ok()?;
+        let origin = self
+            .conn
+            .translate_coordinates(window, self.root, 0, 0)
+            .ok()?
+            .reply()
+            .ok()?;
+        Some(DisplayRect::new(
+            origin.dst_x.into(),
+            origin.dst_y.into(),
+            geometry.width.into(),
+            geometry.height.into(),
+        ))
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+impl WindowFinder {
+    /// Looks up the bounds of the first visible window whose title contains `title`, ignoring case.
+    /// Uses the EWMH client list, so it sees X11 and XWayland windows only.
+    fn find(&mut self, title: &str) -> Option<DisplayRect> {
+        if title.is_empty() {
+            return None;
+        }
+        if self.session.is_none() {
+            self.session = X11Session::connect();
+        }
+        let session = self.session.as_ref()?;
+
+        let Some(clients) = session.clients() else {
+            // Reconnect on the next lookup, e.g. after the X server restarted.
+            self.session = None;
+            return None;
+        };
+        clients
+            .into_iter()
+            .filter(|&window| {
+                session
+                    .title(window)
+                    .is_some_and(|window_title| title_matches(&window_title, title))
+            })
+            .find(|&window| session.is_visible(window))
+            .and_then(|window| session.bounds(window))
+    }
+}
+
+/// Looks up window bounds, keeping whatever platform connection that needs between lookups.
+/// Window lookup is not supported on this platform, so no window is ever found.
+#[cfg(not(any(target_os = "windows", all(unix, not(target_os = "macos")))))]
+#[derive(Default)]
+struct WindowFinder;
+
+#[cfg(not(any(target_os = "windows", all(unix, not(target_os = "macos")))))]
+impl WindowFinder {
+    fn find(&mut self, _title: &str) -> Option<DisplayRect> {
+        None
+    }
+}
+
+#[cfg(all(test, any(target_os = "windows", all(unix, not(target_os = "macos")))))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn titles_match_by_substring_ignoring_case() {
+        assert!(title_matches("Mozilla Firefox", "firefox"));
+        assert!(title_matches("notes.txt - Notepad", "NOTES"));
+        assert!(title_matches("Kalkulačka", "KALKULAČ"));
+        assert!(!title_matches("Mozilla Firefox", "chrome"));
+    }
+
+    #[test]
+    fn an_empty_title_matches_nothing() {
+        assert!(!title_matches("Mozilla Firefox", ""));
+        assert!(!title_matches("", ""));
+    }
+}
@@ -0,0 +1,33 @@
+//! On-screen overlay window
+//! A transparent, click-through window above everything else that shows mapper feedback, such as
+//! the scanning highlight, while the main window is hidden or behind other apps.
+
+use tauri::{AppHandle, WebviewUrl, WebviewWindowBuilder};
+
+/// Label of the overlay window. The frontend renders the overlay instead of the app in it.
+pub const OVERLAY_LABEL: &str = "overlay";
+
+/// Creates the overlay window, hidden, over the primary monitor.
+/// The frontend shows it only while it has something to draw.
+pub fn create_overlay(app_handle: &AppHandle) -> tauri::Result<()> {
+    let window = WebviewWindowBuilder::new(app_handle, OVERLAY_LABEL, WebviewUrl::default())
+        .title("GearVR Controller Bridge Overlay")
+        .transparent(true)
+        .decorations(false)
+        .shadow(false)
+        .always_on_top(true)
+        .skip_taskbar(true)
+        .resizable(false)
+        // Never take focus from the app the controller is typing into.
+        .focused(false)
+        .visible(false)
+        .build()?;
+
+    if let Some(monitor) = window.primary_monitor()? {
+        window.set_position(*monitor.position())?;
+        window.set_size(*monitor.size())?;
+    }
+    // Clicks, including injected ones, go through to whatever is underneath.
+    window.set_ignore_cursor_events(true)?;
+    Ok(())
+}
@@ -10,6 +10,7 @@ use crate::tray;
 use anyhow::Result;
 use log::info;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use tauri::{AppHandle, Manager, State, tray::TrayIcon};
 use tokio::sync::Mutex;
 
@@ -18,6 +19,8 @@ pub struct AppState {
     /// The Bluetooth manager instance
     pub bluetooth_manager: Arc<Mutex<BluetoothManager>>,
     pub mouse_sender: Arc<Mutex<MouseMapperSender>>,
+    /// Whether input injection is paused, readable without locking the mouse sender
+    input_paused: Arc<AtomicBool>,
 }
 
 impl AppState {
@@ -36,12 +39,19 @@ impl AppState {
             initial_mouse_config.unwrap_or_default(),
             initial_keymap_config.unwrap_or_default(),
         );
+        let input_paused = mouse_sender.paused_flag();
         Ok(Self {
             bluetooth_manager: Arc::new(Mutex::new(bluetooth_manager)),
             mouse_sender: Arc::new(Mutex::new(mouse_sender)),
+            input_paused,
         })
     }
 
+    /// Returns whether input injection is currently paused
+    pub fn is_input_paused(&self) -> bool {
+        self.input_paused.load(Ordering::SeqCst)
+    }
+
     /// Gets a reference to the Bluetooth manager
     pub fn get_bluetooth_manager_arc(&self) -> Arc<Mutex<BluetoothManager>> {
         self.bluetooth_manager.clone()
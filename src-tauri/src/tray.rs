@@ -1,13 +1,14 @@
 //! Tray module for handling tray menu internationalization.
 
 use crate::commands;
+use crate::state::AppState;
 use log::error;
 use std::collections::HashMap;
 use std::fs;
 use tauri::{
-    AppHandle, Manager, State,
+    AppHandle, Listener, Manager, State,
     image::Image,
-    menu::{Menu, MenuItem},
+    menu::{CheckMenuItem, Menu, MenuItem},
     path::BaseDirectory,
     tray::{TrayIcon, TrayIconBuilder},
 };
@@ -38,6 +39,15 @@ pub fn create_tray(app_handle: &AppHandle) -> Result<TrayIcon, Box<dyn std::erro
                         .unwrap();
                 }
             }
+            "pause" => {
+                if let Some(app_state) = app.try_state::<AppState>() {
+                    let paused = app_state.is_input_paused();
+                    let mouse_sender = app_state.mouse_sender.clone();
+                    tauri::async_runtime::spawn(async move {
+                        mouse_sender.lock().await.set_paused(!paused).await;
+                    });
+                }
+            }
             "quit" => {
                 app.remove_tray_by_id(app.state::<TrayIcon>().id())
                     .expect("Failed to remove tray icon.");
@@ -52,6 +62,23 @@ pub fn create_tray(app_handle: &AppHandle) -> Result<TrayIcon, Box<dyn std::erro
         error!("Failed to set initial tray menu: {}", e);
     }
 
+    // Keep the pause item in sync when pausing is toggled from the controller or the frontend.
+    // Listeners run synchronously on the emitting thread, which is a tokio worker for events
+    // forwarded from the mapper, so the listener must not block on the async runtime.
+    let listener_handle = app_handle.clone();
+    app_handle.listen("input-paused", move |_event| {
+        let app_handle = listener_handle.clone();
+        tauri::async_runtime::spawn(async move {
+            let lang = commands::get_current_language(app_handle.clone())
+                .await
+                .unwrap_or_else(|_| "en".to_string());
+            let tray_state: State<TrayIcon> = app_handle.state();
+            if let Err(e) = update_tray_menu(&app_handle, &tray_state, &lang) {
+                error!("Failed to update tray menu: {}", e);
+            }
+        });
+    });
+
     // Add a theme change event listener
     if let Some(window) = app_handle.get_webview_window("main") {
         let app_handle = app_handle.clone();
@@ -147,15 +174,33 @@ pub fn update_tray_menu(app_handle: &AppHandle, tray: &TrayIcon, lang: &str) ->
     let show_text = translations
         .get("trayMenu.show")
         .map_or("Show", |s| s.as_str());
+    let pause_text = translations
+        .get("trayMenu.pause")
+        .map_or("Pause Input", |s| s.as_str());
     let quit_text = translations
         .get("trayMenu.quit")
         .map_or("Quit", |s| s.as_str());
 
+    // The app state is not managed yet while the tray is first created
+    let is_paused = app_handle
+        .try_state::<AppState>()
+        .is_some_and(|state| state.is_input_paused());
+
     let show_i = MenuItem::with_id(app_handle, "show", show_text, true, None::<&str>)
         .map_err(|e| e.to_string())?;
+    let pause_i = CheckMenuItem::with_id(
+        app_handle,
+        "pause",
+        pause_text,
+        true,
+        is_paused,
+        None::<&str>,
+    )
+    .map_err(|e| e.to_string())?;
     let quit_i = MenuItem::with_id(app_handle, "quit", quit_text, true, None::<&str>)
         .map_err(|e| e.to_string())?;
-    let menu = Menu::with_items(app_handle, &[&show_i, &quit_i]).map_err(|e| e.to_string())?;
+    let menu =
+        Menu::with_items(app_handle, &[&show_i, &pause_i, &quit_i]).map_err(|e| e.to_string())?;
 
     tray.set_menu(Some(menu)).map_err(|e| e.to_string())
 }
@@ -0,0 +1,215 @@
+import React, { useEffect, useRef, useState } from 'react';
+import { listen } from '@tauri-apps/api/event';
+import { getCurrentWindow } from '@tauri-apps/api/window';
+
+// --- Event payloads sent by the mouse mapper ---
+interface ScanHighlight {
+  actions: string[];
+  index: number;
+  moving: boolean;
+}
+
+type DwellClickKind = 'Left' | 'Right' | 'Double' | 'Drag';
+
+interface DwellProgress {
+  x: number;
+  y: number;
+  progress: number;
+  kind: DwellClickKind;
+}
+
+interface RadialMenu {
+  slices: string[];
+  highlighted: number | null;
+}
+
+interface HandwritingResult {
+  output: string | null;
+  score: number;
+  typed: boolean;
+}
+
+// How long a recognized stroke stays on screen.
+const HANDWRITING_TOAST_MS = 1500;
+
+// A chosen radial menu slice stays lit this long after the menu closes, to confirm the choice.
+const RADIAL_MENU_CONFIRM_MS = 250;
+const RADIAL_MENU_RADIUS = 140;
+
+// A dwell ring disappears if its countdown stops updating, e.g. when dwell clicking is turned off.
+const DWELL_STALE_MS = 500;
+const DWELL_RING_RADIUS = 22;
+
+const DWELL_KIND_LABELS: Record<DwellClickKind, string> = { Left: 'L', Right: 'R', Double: '2×', Drag: '⇲' };
+
+// Pointer positions are physical pixels, except on macOS where they are already in points.
+const POINTER_IN_POINTS = navigator.userAgent.includes('Mac');
+
+const DwellRing: React.FC<{ dwell: DwellProgress; origin: { x: number; y: number }; scale: number }> = ({ dwell, origin, scale }) => {
+    const x = POINTER_IN_POINTS ? dwell.x - origin.x / scale : (dwell.x - origin.x) / scale;
+    const y = POINTER_IN_POINTS ? dwell.y - origin.y / scale : (dwell.y - origin.y) / scale;
+    const circumference = 2 * Math.PI * DWELL_RING_RADIUS;
+    const size = DWELL_RING_RADIUS * 2 + 8;
+
+    return (
+        <div style={{ ...styles.dwellRing, left: x - size / 2, top: y - size / 2, width: size, height: size }}>
+            <svg width={size} height={size}>
+                <circle cx={size / 2} cy={size / 2} r={DWELL_RING_RADIUS} fill="none" stroke="rgba(0, 0, 0, 0.5)" strokeWidth={6} />
+                <circle
+                    cx={size / 2} cy={size / 2} r={DWELL_RING_RADIUS}
+                    fill="none" stroke="#00ffcc" strokeWidth={4}
+                    strokeDasharray={circumference}
+                    strokeDashoffset={circumference * (1 - dwell.progress)}
+                    transform={`rotate(-90 ${size / 2} ${size / 2})`}
+                />
+            </svg>
+            <span style={styles.dwellKind}>{DWELL_KIND_LABELS[dwell.kind]}</span>
+        </div>
+    );
+};
+
+// Slices sit clockwise from the top, matching how the mapper picks them.
+const RadialMenuView: React.FC<{ menu: RadialMenu }> = ({ menu }) => (
+    <div style={styles.radialMenu}>
+        {menu.slices.map((slice, index) => {
+            const angle = (2 * Math.PI * index) / menu.slices.length;
+            return (
+                <div
+                    key={`${index}-${slice}`}
+                    style={{
+                        ...(index === menu.highlighted ? styles.radialSliceActive : styles.radialSlice),
+                        left: RADIAL_MENU_RADIUS + RADIAL_MENU_RADIUS * 0.7 * Math.sin(angle),
+                        top: RADIAL_MENU_RADIUS - RADIAL_MENU_RADIUS * 0.7 * Math.cos(angle),
+                    }}
+                >
+                    {slice}
+                </div>
+            );
+        })}
+    </div>
+);
+
+// --- On-screen overlay ---
+// Runs in the transparent, click-through overlay window and shows itself only while it has something to draw.
+const Overlay: React.FC = () => {
+  const [scan, setScan] = useState<ScanHighlight | null>(null);
+  const [dwell, setDwell] = useState<DwellProgress | null>(null);
+  const [origin, setOrigin] = useState({ x: 0, y: 0 });
+  const [scale, setScale] = useState(1);
+  const [radialMenu, setRadialMenu] = useState<RadialMenu | null>(null);
+  const [handwriting, setHandwriting] = useState<HandwritingResult | null>(null);
+  const dwellTimeoutRef = useRef<any>(null);
+  const handwritingTimeoutRef = useRef<any>(null);
+  const radialMenuTimeoutRef = useRef<any>(null);
+
+  // The page background is painted by the app stylesheet; the overlay must stay see-through.
+  useEffect(() => {
+    document.documentElement.style.background = 'transparent';
+    document.body.style.background = 'transparent';
+  }, []);
+
+  // Where the overlay sits on the desktop, to place pointer-relative feedback.
+  useEffect(() => {
+    const overlayWindow = getCurrentWindow();
+    overlayWindow.outerPosition().then((position) => setOrigin({ x: position.x, y: position.y })).catch(console.error);
+    overlayWindow.scaleFactor().then(setScale).catch(console.error);
+  }, []);
+
+  useEffect(() => {
+    const unlistenScan = listen<ScanHighlight | null>('scan-highlight', (event) => setScan(event.payload));
+    const unlistenDwell = listen<DwellProgress>('dwell-progress', (event) => {
+        clearTimeout(dwellTimeoutRef.current);
+        // Progress 0 means the pointer moved away and the countdown was cancelled.
+        if (event.payload.progress <= 0) {
+            setDwell(null);
+            return;
+        }
+        setDwell(event.payload);
+        dwellTimeoutRef.current = setTimeout(() => setDwell(null), DWELL_STALE_MS);
+    });
+    const unlistenRadialOpened = listen<string[]>('radial-menu-opened', (event) => {
+        clearTimeout(radialMenuTimeoutRef.current);
+        setRadialMenu({ slices: event.payload, highlighted: null });
+    });
+    const unlistenRadialHighlight = listen<number | null>('radial-menu-highlight', (event) => {
+        setRadialMenu(menu => menu && { ...menu, highlighted: event.payload });
+    });
+    const unlistenRadialClosed = listen<number | null>('radial-menu-closed', (event) => {
+        if (event.payload === null) {
+            setRadialMenu(null);
+            return;
+        }
+        const selected = event.payload;
+        setRadialMenu(menu => menu && { ...menu, highlighted: selected });
+        radialMenuTimeoutRef.current = setTimeout(() => setRadialMenu(null), RADIAL_MENU_CONFIRM_MS);
+    });
+    const unlistenHandwriting = listen<HandwritingResult>('handwriting-recognized', (event) => {
+        clearTimeout(handwritingTimeoutRef.current);
+        setHandwriting(event.payload);
+        handwritingTimeoutRef.current = setTimeout(() => setHandwriting(null), HANDWRITING_TOAST_MS);
+    });
+
+    return () => {
+      unlistenScan.then(f => f());
+      unlistenDwell.then(f => f());
+      unlistenRadialOpened.then(f => f());
+      unlistenRadialHighlight.then(f => f());
+      unlistenRadialClosed.then(f => f());
+      unlistenHandwriting.then(f => f());
+      clearTimeout(dwellTimeoutRef.current);
+      clearTimeout(radialMenuTimeoutRef.current);
+      clearTimeout(handwritingTimeoutRef.current);
+    };
+  }, []);
+
+  const isVisible = scan !== null || dwell !== null || radialMenu !== null || handwriting !== null;
+  useEffect(() => {
+    const overlayWindow = getCurrentWindow();
+    (isVisible ? overlayWindow.show() : overlayWindow.hide()).catch(console.error);
+  }, [isVisible]);
+
+  return (
+    <div style={styles.page}>
+      {scan && (
+        <div style={styles.scanBar}>
+          {scan.actions.map((action, index) => (
+            <div
+              key={`${index}-${action}`}
+              style={index === scan.index ? (scan.moving ? styles.scanItemMoving : styles.scanItemActive) : styles.scanItem}
+            >
+              {action}
+            </div>
+          ))}
+        </div>
+      )}
+      {radialMenu && <RadialMenuView menu={radialMenu} />}
+      {dwell && <DwellRing dwell={dwell} origin={origin} scale={scale} />}
+      {handwriting && (
+        // Strokes below the minimum score are shown dimmed, since nothing was typed.
+        <div style={handwriting.typed ? styles.handwritingToast : styles.handwritingToastRejected}>
+          <span style={styles.handwritingOutput}>{handwriting.output ?? '?'}</span>
+          <span style={styles.handwritingScore}>{Math.round(handwriting.score * 100)}%</span>
+        </div>
+      )}
+    </div>
+  );
+};
+
+const styles: { [key: string]: React.CSSProperties } = {
+    page: { position: 'fixed', inset: 0, pointerEvents: 'none', overflow: 'hidden', fontFamily: 'Inter, Avenir, Helvetica, Arial, sans-serif' },
+    scanBar: { position: 'absolute', top: '24px', left: '50%', transform: 'translateX(-50%)', display: 'flex', gap: '8px', padding: '10px', borderRadius: '12px', backgroundColor: 'rgba(30, 30, 30, 0.85)', boxShadow: '0 4px 12px rgba(0, 0, 0, 0.4)' },
+    scanItem: { padding: '12px 20px', borderRadius: '8px', fontSize: '1.4rem', color: '#eee', border: '2px solid #555' },
+    scanItemActive: { padding: '12px 20px', borderRadius: '8px', fontSize: '1.4rem', color: '#1e1e1e', backgroundColor: '#00ffcc', border: '2px solid #00ffcc', fontWeight: 'bold' },
+    scanItemMoving: { padding: '12px 20px', borderRadius: '8px', fontSize: '1.4rem', color: '#1e1e1e', backgroundColor: '#ffc107', border: '2px solid #ffc107', fontWeight: 'bold' },
+    radialMenu: { position: 'absolute', left: '50%', top: '50%', width: `${RADIAL_MENU_RADIUS * 2}px`, height: `${RADIAL_MENU_RADIUS * 2}px`, transform: 'translate(-50%, -50%)', borderRadius: '50%', backgroundColor: 'rgba(30, 30, 30, 0.85)', boxShadow: '0 4px 12px rgba(0, 0, 0, 0.4)' },
+    radialSlice: { position: 'absolute', transform: 'translate(-50%, -50%)', padding: '8px 14px', borderRadius: '8px', fontSize: '1.1rem', color: '#eee', border: '2px solid #555', whiteSpace: 'nowrap' },
+    radialSliceActive: { position: 'absolute', transform: 'translate(-50%, -50%)', padding: '8px 14px', borderRadius: '8px', fontSize: '1.1rem', color: '#1e1e1e', backgroundColor: '#00ffcc', border: '2px solid #00ffcc', fontWeight: 'bold', whiteSpace: 'nowrap' },
+    handwritingToast: { position: 'absolute', bottom: '48px', left: '50%', transform: 'translateX(-50%)', display: 'flex', alignItems: 'baseline', gap: '12px', padding: '12px 24px', borderRadius: '12px', color: '#fff', backgroundColor: 'rgba(30, 30, 30, 0.85)', border: '2px solid #00ffcc', boxShadow: '0 4px 12px rgba(0, 0, 0, 0.4)' },
+    handwritingToastRejected: { position: 'absolute', bottom: '48px', left: '50%', transform: 'translateX(-50%)', display: 'flex', alignItems: 'baseline', gap: '12px', padding: '12px 24px', borderRadius: '12px', color: '#999', backgroundColor: 'rgba(30, 30, 30, 0.85)', border: '2px solid #555', boxShadow: '0 4px 12px rgba(0, 0, 0, 0.4)' },
+    handwritingOutput: { fontSize: '2rem', fontWeight: 'bold' },
+    handwritingScore: { fontSize: '1rem' },
+    dwellRing: { position: 'absolute', display: 'flex', alignItems: 'center', justifyContent: 'center' },
+    dwellKind: { position: 'absolute', color: '#fff', fontWeight: 'bold', fontSize: '0.9rem', textShadow: '0 0 3px #000' },
+};
+
+export default Overlay;
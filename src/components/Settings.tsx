@@ -1,5 +1,6 @@
 import React, { useState, useEffect, useCallback } from 'react';
 import { useTranslation } from 'react-i18next';
+import type { TFunction } from 'i18next';
 import { invoke } from '@tauri-apps/api/core';
 import { listen } from '@tauri-apps/api/event';
 
@@ -16,24 +17,111 @@ interface GyroCalibration {
     zero_bias: Vector3;
 }
 
+type TremorSuppression =
+  | { type: 'Off' }
+  | { type: 'BandStop'; low_hz: number; high_hz: number; strength: number }
+  | { type: 'MovingAverage'; window: number };
+
 interface ControllerConfig {
   sensor_low_pass_alpha: number;
   delta_t_smoothing_alpha: number;
   madgwick_beta: number;
   orientation_smoothing_factor: number;
   local_earth_mag_field: number;
+  tremor_suppression: TremorSuppression;
   mag_calibration: MagCalibration;
   gyro_calibration: GyroCalibration;
 }
 
 // --- New split config types ---
+type MouseMode = 'AirMouse' | 'Touchpad' | 'GyroMouse' | 'TiltKeys' | 'GyroAim' | 'Handwriting' | 'Chord' | 'Scanning';
+
+const ALL_MOUSE_MODES: MouseMode[] = ['AirMouse', 'Touchpad', 'GyroMouse', 'TiltKeys', 'GyroAim', 'Handwriting', 'Chord', 'Scanning'];
+
+type MappingRegion =
+  | { type: 'Rect'; x: number; y: number; width: number; height: number }
+  | { type: 'Window'; title: string };
+
+type ControllerButton = 'Trigger' | 'Home' | 'Back' | 'VolumeUp' | 'VolumeDown' | 'Touchpad';
+
+interface GyroAimConfig {
+  sensitivity: number;
+  counts_per_degree: number;
+  deadzone: number;
+  ratchet_button: ControllerButton | null;
+  flick_stick: boolean;
+  flick_deadzone: number;
+  flick_time_ms: number;
+}
+
+interface TiltScrollConfig {
+  button: ControllerButton | null;
+  input: 'Rate' | 'Angle';
+  twist: 'None' | 'Zoom' | 'HorizontalScroll';
+  deadzone: number;
+  sensitivity: number;
+  acceleration: number;
+}
+
+interface TiltKeysConfig {
+  press_angle: number;
+  release_angle: number;
+  pulse: boolean;
+  full_angle: number;
+  pulse_period_ms: number;
+}
+
 interface MouseConfig {
-  mode: 'AirMouse' | 'Touchpad';
+  mode: MouseMode;
+  enabled_modes: MouseMode[];
   touchpad_sensitivity: number;
   touchpad_acceleration: number;
   touchpad_acceleration_threshold: number;
+  touchpad_absolute: boolean;
+  mapping_region: MappingRegion | null;
+  tilt_keys: TiltKeysConfig;
+  gyro_aim: GyroAimConfig;
+  tilt_scroll: TiltScrollConfig;
   air_mouse_fov: number;
   air_mouse_activation_threshold: number;
+  click_stabilization_ms: number;
+  dwell_click_enabled: boolean;
+  dwell_click_time_ms: number;
+  adaptive_smoothing: boolean;
+  smoothing_min_cutoff: number;
+  smoothing_beta: number;
+  input_backend: 'Enigo' | 'Uinput';
+  output_mode: 'Mouse' | 'Gamepad';
+  scroll_amount: number;
+  persist_mode_changes: boolean;
+}
+
+interface TiltKeyBindings {
+  left: string | null;
+  right: string | null;
+  forward: string | null;
+  backward: string | null;
+}
+
+type ModeCycleGesture =
+  | { type: 'Disabled' }
+  | { type: 'DoubleClick'; button: ControllerButton; window_ms: number }
+  | { type: 'Hold'; button: ControllerButton; hold_ms: number };
+
+interface RadialMenuConfig {
+  button: ControllerButton | null;
+  slices: string[];
+  touchpad_deadzone: number;
+  tilt_deadzone: number;
+}
+
+type ChordKey = 'Trigger' | 'Back' | 'VolumeUp' | 'VolumeDown' | 'TouchpadUp' | 'TouchpadDown' | 'TouchpadLeft' | 'TouchpadRight';
+
+const ALL_CHORD_KEYS: ChordKey[] = ['Trigger', 'Back', 'VolumeUp', 'VolumeDown', 'TouchpadUp', 'TouchpadDown', 'TouchpadLeft', 'TouchpadRight'];
+
+interface ChordBinding {
+  keys: ChordKey[];
+  output: string;
 }
 
 interface KeymapConfig {
@@ -43,8 +131,34 @@ interface KeymapConfig {
   volume_up: string | null;
   volume_down: string | null;
   touchpad: string | null;
+  pause_chord: ControllerButton[];
+  pause_hold_ms: number;
+  mode_cycle: ModeCycleGesture;
+  tilt_keys: TiltKeyBindings;
+  radial_menu: RadialMenuConfig;
+  chords: ChordBinding[];
 }
 
+// Controller buttons with a binding in the keymap, in display order.
+const BINDING_KEYS = ['trigger', 'home', 'back', 'volume_up', 'volume_down', 'touchpad'] as const;
+type BindingKey = typeof BINDING_KEYS[number];
+
+// Controller buttons with the keymap entry whose label names them.
+const CONTROLLER_BUTTONS: { button: ControllerButton; labelKey: BindingKey }[] = [
+  { button: 'Trigger', labelKey: 'trigger' },
+  { button: 'Home', labelKey: 'home' },
+  { button: 'Back', labelKey: 'back' },
+  { button: 'VolumeUp', labelKey: 'volume_up' },
+  { button: 'VolumeDown', labelKey: 'volume_down' },
+  { button: 'Touchpad', labelKey: 'touchpad' },
+];
+
+// Bindings handled by the mapper itself. They cannot be captured from the keyboard, so they are picked from a list.
+const MAPPER_ACTIONS: { binding: string; labelKey: string }[] = [
+  { binding: 'Next display', labelKey: 'nextDisplay' },
+  { binding: 'Dwell click', labelKey: 'dwellClick' },
+];
+
 interface SettingsProps {
   onBack: () => void;
 }
@@ -169,6 +283,627 @@ const VectorDisplay: React.FC<{ vector: Vector3, labels?: [string, string, strin
     </div>
 );
 
+const EnabledModesEditor: React.FC<{
+  modes: MouseMode[];
+  onChange: (modes: MouseMode[]) => void;
+  t: TFunction;
+}> = ({ modes, onChange, t }) => {
+    const move = (index: number, offset: number) => {
+        const reordered = [...modes];
+        [reordered[index], reordered[index + offset]] = [reordered[index + offset], reordered[index]];
+        onChange(reordered);
+    };
+    const available = ALL_MOUSE_MODES.filter((mode) => !modes.includes(mode));
+
+    return (
+        <div style={styles.formGroup}>
+            <label style={{ ...styles.switchLabel, marginBottom: '10px' }}>{t('settings.mouse.enabledModes.title')}</label>
+            {modes.map((mode, index) => (
+                <div key={mode} style={styles.modeRow}>
+                    <span style={styles.modeName}>{index + 1}. {t(`settings.mouse.modes.${mode}`)}</span>
+                    <button style={styles.smallButton} disabled={index === 0} onClick={() => move(index, -1)} title={t('settings.mouse.enabledModes.moveUp')}>↑</button>
+                    <button style={styles.smallButton} disabled={index === modes.length - 1} onClick={() => move(index, 1)} title={t('settings.mouse.enabledModes.moveDown')}>↓</button>
+                    <button style={styles.smallButton} onClick={() => onChange(modes.filter((m) => m !== mode))} title={t('settings.mouse.enabledModes.remove')}>✕</button>
+                </div>
+            ))}
+            {available.length > 0 && (
+                <select style={styles.select} value="" onChange={(e) => onChange([...modes, e.target.value as MouseMode])}>
+                    <option value="" disabled>{t('settings.mouse.enabledModes.add')}</option>
+                    {available.map((mode) => (
+                        <option key={mode} value={mode}>{t(`settings.mouse.modes.${mode}`)}</option>
+                    ))}
+                </select>
+            )}
+        </div>
+    );
+};
+
+const MappingRegionEditor: React.FC<{
+  region: MappingRegion | null;
+  onChange: (region: MappingRegion | null) => void;
+  t: TFunction;
+}> = ({ region, onChange, t }) => {
+    // Edits are kept locally and saved when a field loses focus, not on every keystroke.
+    const [draft, setDraft] = useState<MappingRegion | null>(region);
+    useEffect(() => setDraft(region), [region]);
+
+    const changeType = (type: string) => {
+        switch (type) {
+            case 'Rect': return onChange({ type: 'Rect', x: 0, y: 0, width: 1920, height: 1080 });
+            case 'Window': return onChange({ type: 'Window', title: '' });
+            default: return onChange(null);
+        }
+    };
+
+    return (
+        <div style={styles.formGroup}>
+            <div style={{ ...styles.formGroupRow, width: '100%', marginBottom: '10px' }}>
+                <label style={styles.switchLabel}>{t('settings.mouse.mappingRegion.title')}</label>
+                <select style={styles.select} value={region?.type ?? 'None'} onChange={(e) => changeType(e.target.value)}>
+                    <option value="None">{t('settings.mouse.mappingRegion.none')}</option>
+                    <option value="Rect">{t('settings.mouse.mappingRegion.rect')}</option>
+                    <option value="Window">{t('settings.mouse.mappingRegion.window')}</option>
+                </select>
+            </div>
+            {draft?.type === 'Rect' && (
+                <div style={styles.regionFields}>
+                    {(['x', 'y', 'width', 'height'] as const).map((field) => (
+                        <label key={field} style={styles.regionField}>
+                            {t(`settings.mouse.mappingRegion.${field}`)}
+                            <input
+                                type="number"
+                                style={styles.input}
+                                value={draft[field]}
+                                onChange={(e) => setDraft({ ...draft, [field]: parseInt(e.target.value, 10) || 0 })}
+                                onBlur={() => onChange(draft)}
+                            />
+                        </label>
+                    ))}
+                </div>
+            )}
+            {draft?.type === 'Window' && (
+                <input
+                    type="text"
+                    style={styles.input}
+                    value={draft.title}
+                    placeholder={t('settings.mouse.mappingRegion.windowTitle')}
+                    onChange={(e) => setDraft({ ...draft, title: e.target.value })}
+                    onBlur={() => onChange(draft)}
+                />
+            )}
+        </div>
+    );
+};
+
+const TremorSuppressionEditor: React.FC<{
+  value: TremorSuppression;
+  onChange: (value: TremorSuppression) => void;
+  t: TFunction;
+}> = ({ value, onChange, t }) => {
+    // Edits are kept locally and saved when a field loses focus, not on every keystroke.
+    const [draft, setDraft] = useState<TremorSuppression>(value);
+    useEffect(() => setDraft(value), [value]);
+
+    const changeType = (type: string) => {
+        switch (type) {
+            case 'BandStop': return onChange({ type: 'BandStop', low_hz: 4, high_hz: 12, strength: 1 });
+            case 'MovingAverage': return onChange({ type: 'MovingAverage', window: 5 });
+            default: return onChange({ type: 'Off' });
+        }
+    };
+
+    return (
+        <div style={styles.formGroup}>
+            <div style={{ ...styles.formGroupRow, width: '100%', marginBottom: '10px' }}>
+                <label style={styles.switchLabel}>{t('settings.controller.tremor.title')}</label>
+                <select style={styles.select} value={value.type} onChange={(e) => changeType(e.target.value)}>
+                    {(['Off', 'BandStop', 'MovingAverage'] as const).map((type) => (
+                        <option key={type} value={type}>{t(`settings.controller.tremor.${type}`)}</option>
+                    ))}
+                </select>
+            </div>
+            {draft.type === 'BandStop' && (
+                <div style={styles.regionFields}>
+                    {(['low_hz', 'high_hz', 'strength'] as const).map((field) => (
+                        <label key={field} style={styles.regionField}>
+                            {t(`settings.controller.tremor.${field}`)}
+                            <input
+                                type="number"
+                                step={field === 'strength' ? 0.1 : 0.5}
+                                style={styles.input}
+                                value={draft[field]}
+                                onChange={(e) => setDraft({ ...draft, [field]: parseFloat(e.target.value) || 0 })}
+                                onBlur={() => onChange(draft)}
+                            />
+                        </label>
+                    ))}
+                </div>
+            )}
+            {draft.type === 'MovingAverage' && (
+                <label style={styles.regionField}>
+                    {t('settings.controller.tremor.window')}
+                    <input
+                        type="number"
+                        min={1}
+                        style={styles.input}
+                        value={draft.window}
+                        onChange={(e) => setDraft({ ...draft, window: Math.max(1, parseInt(e.target.value, 10) || 1) })}
+                        onBlur={() => onChange(draft)}
+                    />
+                </label>
+            )}
+        </div>
+    );
+};
+
+// Picks a controller button. With `allowNone`, the first option turns the feature off.
+const ButtonSelect: React.FC<{
+  label: string;
+  value: ControllerButton | null;
+  onChange: (value: ControllerButton | null) => void;
+  allowNone?: boolean;
+  t: TFunction;
+}> = ({ label, value, onChange, allowNone = false, t }) => (
+    <div style={styles.formGroupRow}>
+        <label style={styles.switchLabel}>{label}</label>
+        <select style={styles.select} value={value ?? ''} onChange={(e) => onChange((e.target.value || null) as ControllerButton | null)}>
+            {allowNone && <option value="">{t('settings.keymap.none')}</option>}
+            {CONTROLLER_BUTTONS.map(({ button, labelKey }) => (
+                <option key={button} value={button}>{t(`settings.keymap.keys.${labelKey}`)}</option>
+            ))}
+        </select>
+    </div>
+);
+
+// A binding typed as text, for bindings outside the per-button keymap. Empty means unbound.
+const BindingInput: React.FC<{
+  value: string | null;
+  onChange: (value: string | null) => void;
+  placeholder: string;
+}> = ({ value, onChange, placeholder }) => {
+    // Edits are kept locally and saved when the field loses focus, not on every keystroke.
+    const [draft, setDraft] = useState(value ?? '');
+    useEffect(() => setDraft(value ?? ''), [value]);
+
+    return (
+        <input
+            type="text"
+            style={styles.input}
+            value={draft}
+            placeholder={placeholder}
+            onChange={(e) => setDraft(e.target.value)}
+            onBlur={() => onChange(draft.trim() || null)}
+        />
+    );
+};
+
+const TiltKeysEditor: React.FC<{
+  value: TiltKeysConfig;
+  onChange: (value: TiltKeysConfig) => void;
+  t: TFunction;
+}> = ({ value, onChange, t }) => {
+    const [draft, setDraft] = useState<TiltKeysConfig>(value);
+    useEffect(() => setDraft(value), [value]);
+
+    return (
+        <>
+            <h4 style={styles.subHeading4}>{t('settings.mouse.tiltKeys.title')}</h4>
+            <Slider
+                label={t('settings.mouse.tiltKeys.pressAngle')}
+                min={1} max={60} step={1} value={draft.press_angle}
+                onChange={(v) => setDraft({ ...draft, press_angle: v, release_angle: Math.min(draft.release_angle, v) })}
+                onAfterChange={() => onChange(draft)}
+                precision={0}
+            />
+            <Slider
+                label={t('settings.mouse.tiltKeys.releaseAngle')}
+                min={0} max={60} step={1} value={draft.release_angle}
+                onChange={(v) => setDraft({ ...draft, release_angle: Math.min(v, draft.press_angle) })}
+                onAfterChange={() => onChange(draft)}
+                precision={0}
+            />
+            <Switch
+                label={t('settings.mouse.tiltKeys.pulse')}
+                checked={draft.pulse}
+                onChange={(isChecked) => onChange({ ...draft, pulse: isChecked })}
+            />
+            {draft.pulse && (
+                <>
+                    <Slider
+                        label={t('settings.mouse.tiltKeys.fullAngle')}
+                        min={1} max={90} step={1} value={draft.full_angle}
+                        onChange={(v) => setDraft({ ...draft, full_angle: v })}
+                        onAfterChange={() => onChange(draft)}
+                        precision={0}
+                    />
+                    <Slider
+                        label={t('settings.mouse.tiltKeys.pulsePeriod')}
+                        min={50} max={1000} step={10} value={draft.pulse_period_ms}
+                        onChange={(v) => setDraft({ ...draft, pulse_period_ms: v })}
+                        onAfterChange={() => onChange(draft)}
+                        precision={0}
+                    />
+                </>
+            )}
+        </>
+    );
+};
+
+const GyroAimEditor: React.FC<{
+  value: GyroAimConfig;
+  onChange: (value: GyroAimConfig) => void;
+  t: TFunction;
+}> = ({ value, onChange, t }) => {
+    const [draft, setDraft] = useState<GyroAimConfig>(value);
+    useEffect(() => setDraft(value), [value]);
+
+    return (
+        <>
+            <h4 style={styles.subHeading4}>{t('settings.mouse.gyroAim.title')}</h4>
+            <Slider
+                label={t('settings.mouse.gyroAim.sensitivity')}
+                min={0.1} max={10} step={0.1} value={draft.sensitivity}
+                onChange={(v) => setDraft({ ...draft, sensitivity: v })}
+                onAfterChange={() => onChange(draft)}
+                precision={1}
+            />
+            <div style={styles.formGroup}>
+                <label style={styles.switchLabel}>{t('settings.mouse.gyroAim.countsPerDegree')}</label>
+                <input
+                    type="number"
+                    step={0.01}
+                    style={styles.input}
+                    value={draft.counts_per_degree}
+                    onChange={(e) => setDraft({ ...draft, counts_per_degree: parseFloat(e.target.value) || 0 })}
+                    onBlur={() => onChange(draft)}
+                />
+            </div>
+            <Slider
+                label={t('settings.mouse.gyroAim.deadzone')}
+                min={0} max={10} step={0.1} value={draft.deadzone}
+                onChange={(v) => setDraft({ ...draft, deadzone: v })}
+                onAfterChange={() => onChange(draft)}
+                precision={1}
+            />
+            <ButtonSelect
+                t={t}
+                allowNone
+                label={t('settings.mouse.gyroAim.ratchetButton')}
+                value={draft.ratchet_button}
+                onChange={(button) => onChange({ ...draft, ratchet_button: button })}
+            />
+            <Switch
+                label={t('settings.mouse.gyroAim.flickStick')}
+                checked={draft.flick_stick}
+                onChange={(isChecked) => onChange({ ...draft, flick_stick: isChecked })}
+            />
+            {draft.flick_stick && (
+                <>
+                    <Slider
+                        label={t('settings.mouse.gyroAim.flickDeadzone')}
+                        min={0} max={0.95} step={0.05} value={draft.flick_deadzone}
+                        onChange={(v) => setDraft({ ...draft, flick_deadzone: v })}
+                        onAfterChange={() => onChange(draft)}
+                        precision={2}
+                    />
+                    <Slider
+                        label={t('settings.mouse.gyroAim.flickTime')}
+                        min={0} max={500} step={10} value={draft.flick_time_ms}
+                        onChange={(v) => setDraft({ ...draft, flick_time_ms: v })}
+                        onAfterChange={() => onChange(draft)}
+                        precision={0}
+                    />
+                </>
+            )}
+        </>
+    );
+};
+
+const TiltScrollEditor: React.FC<{
+  value: TiltScrollConfig;
+  onChange: (value: TiltScrollConfig) => void;
+  t: TFunction;
+}> = ({ value, onChange, t }) => {
+    const [draft, setDraft] = useState<TiltScrollConfig>(value);
+    useEffect(() => setDraft(value), [value]);
+
+    return (
+        <>
+            <h4 style={styles.subHeading4}>{t('settings.mouse.tiltScroll.title')}</h4>
+            <ButtonSelect
+                t={t}
+                allowNone
+                label={t('settings.mouse.tiltScroll.button')}
+                value={draft.button}
+                onChange={(button) => onChange({ ...draft, button })}
+            />
+            {draft.button !== null && (
+                <>
+                    <div style={styles.formGroupRow}>
+                        <label style={styles.switchLabel}>{t('settings.mouse.tiltScroll.input')}</label>
+                        <select style={styles.select} value={draft.input} onChange={(e) => onChange({ ...draft, input: e.target.value as TiltScrollConfig['input'] })}>
+                            {(['Rate', 'Angle'] as const).map((input) => (
+                                <option key={input} value={input}>{t(`settings.mouse.tiltScroll.inputs.${input}`)}</option>
+                            ))}
+                        </select>
+                    </div>
+                    <div style={styles.formGroupRow}>
+                        <label style={styles.switchLabel}>{t('settings.mouse.tiltScroll.twist')}</label>
+                        <select style={styles.select} value={draft.twist} onChange={(e) => onChange({ ...draft, twist: e.target.value as TiltScrollConfig['twist'] })}>
+                            {(['None', 'Zoom', 'HorizontalScroll'] as const).map((twist) => (
+                                <option key={twist} value={twist}>{t(`settings.mouse.tiltScroll.twists.${twist}`)}</option>
+                            ))}
+                        </select>
+                    </div>
+                    <Slider
+                        label={t('settings.mouse.tiltScroll.deadzone')}
+                        min={0} max={30} step={0.5} value={draft.deadzone}
+                        onChange={(v) => setDraft({ ...draft, deadzone: v })}
+                        onAfterChange={() => onChange(draft)}
+                        precision={1}
+                    />
+                    <Slider
+                        label={t('settings.mouse.tiltScroll.sensitivity')}
+                        min={0.01} max={2} step={0.01} value={draft.sensitivity}
+                        onChange={(v) => setDraft({ ...draft, sensitivity: v })}
+                        onAfterChange={() => onChange(draft)}
+                        precision={2}
+                    />
+                    <Slider
+                        label={t('settings.mouse.tiltScroll.acceleration')}
+                        min={0.5} max={3} step={0.1} value={draft.acceleration}
+                        onChange={(v) => setDraft({ ...draft, acceleration: v })}
+                        onAfterChange={() => onChange(draft)}
+                        precision={1}
+                    />
+                </>
+            )}
+        </>
+    );
+};
+
+const TiltKeyBindingsEditor: React.FC<{
+  value: TiltKeyBindings;
+  onChange: (value: TiltKeyBindings) => void;
+  t: TFunction;
+}> = ({ value, onChange, t }) => (
+    <>
+        <h4 style={styles.subHeading4}>{t('settings.keymap.tiltKeys.title')}</h4>
+        <div style={styles.regionFields}>
+            {(['left', 'right', 'forward', 'backward'] as const).map((direction) => (
+                <label key={direction} style={styles.regionField}>
+                    {t(`settings.keymap.tiltKeys.${direction}`)}
+                    <BindingInput
+                        value={value[direction]}
+                        placeholder={t('settings.keymap.none')}
+                        onChange={(binding) => onChange({ ...value, [direction]: binding })}
+                    />
+                </label>
+            ))}
+        </div>
+    </>
+);
+
+const ModeCycleEditor: React.FC<{
+  value: ModeCycleGesture;
+  onChange: (value: ModeCycleGesture) => void;
+  t: TFunction;
+}> = ({ value, onChange, t }) => {
+    const [draft, setDraft] = useState<ModeCycleGesture>(value);
+    useEffect(() => setDraft(value), [value]);
+
+    const changeType = (type: string) => {
+        const button = draft.type === 'Disabled' ? 'Home' : draft.button;
+        switch (type) {
+            case 'DoubleClick': return onChange({ type: 'DoubleClick', button, window_ms: 300 });
+            case 'Hold': return onChange({ type: 'Hold', button, hold_ms: 800 });
+            default: return onChange({ type: 'Disabled' });
+        }
+    };
+
+    return (
+        <>
+            <h4 style={styles.subHeading4}>{t('settings.keymap.modeCycle.title')}</h4>
+            <div style={styles.formGroupRow}>
+                <label style={styles.switchLabel}>{t('settings.keymap.modeCycle.gesture')}</label>
+                <select style={styles.select} value={value.type} onChange={(e) => changeType(e.target.value)}>
+                    {(['Disabled', 'DoubleClick', 'Hold'] as const).map((type) => (
+                        <option key={type} value={type}>{t(`settings.keymap.modeCycle.${type}`)}</option>
+                    ))}
+                </select>
+            </div>
+            {draft.type !== 'Disabled' && (
+                <ButtonSelect
+                    t={t}
+                    label={t('settings.keymap.modeCycle.button')}
+                    value={draft.button}
+                    onChange={(button) => button && onChange({ ...draft, button })}
+                />
+            )}
+            {draft.type === 'DoubleClick' && (
+                <Slider
+                    label={t('settings.keymap.modeCycle.window')}
+                    min={100} max={1000} step={10} value={draft.window_ms}
+                    onChange={(v) => setDraft({ ...draft, window_ms: v })}
+                    onAfterChange={() => onChange(draft)}
+                    precision={0}
+                />
+            )}
+            {draft.type === 'Hold' && (
+                <Slider
+                    label={t('settings.keymap.modeCycle.holdTime')}
+                    min={200} max={3000} step={50} value={draft.hold_ms}
+                    onChange={(v) => setDraft({ ...draft, hold_ms: v })}
+                    onAfterChange={() => onChange(draft)}
+                    precision={0}
+                />
+            )}
+        </>
+    );
+};
+
+const PauseChordEditor: React.FC<{
+  chord: ControllerButton[];
+  holdMs: number;
+  onChange: (chord: ControllerButton[], holdMs: number) => void;
+  t: TFunction;
+}> = ({ chord, holdMs, onChange, t }) => {
+    const [draftHoldMs, setDraftHoldMs] = useState(holdMs);
+    useEffect(() => setDraftHoldMs(holdMs), [holdMs]);
+
+    const toggle = (button: ControllerButton) => {
+        const updated = chord.includes(button)
+            ? chord.filter((b) => b !== button)
+            : CONTROLLER_BUTTONS.map((b) => b.button).filter((b) => b === button || chord.includes(b));
+        onChange(updated, draftHoldMs);
+    };
+
+    return (
+        <>
+            <h4 style={styles.subHeading4}>{t('settings.keymap.pauseChord.title')}</h4>
+            <div style={styles.formGroup}>
+                <label style={{ ...styles.switchLabel, marginBottom: '10px' }}>{t('settings.keymap.pauseChord.buttons')}</label>
+                <div style={styles.regionFields}>
+                    {CONTROLLER_BUTTONS.map(({ button, labelKey }) => (
+                        <label key={button} style={styles.checkboxLabel}>
+                            <input type="checkbox" checked={chord.includes(button)} onChange={() => toggle(button)} />
+                            {t(`settings.keymap.keys.${labelKey}`)}
+                        </label>
+                    ))}
+                </div>
+            </div>
+            {chord.length > 0 && (
+                <Slider
+                    label={t('settings.keymap.pauseChord.holdTime')}
+                    min={200} max={5000} step={100} value={draftHoldMs}
+                    onChange={setDraftHoldMs}
+                    onAfterChange={() => onChange(chord, draftHoldMs)}
+                    precision={0}
+                />
+            )}
+        </>
+    );
+};
+
+const RadialMenuEditor: React.FC<{
+  value: RadialMenuConfig;
+  onChange: (value: RadialMenuConfig) => void;
+  t: TFunction;
+}> = ({ value, onChange, t }) => {
+    const [draft, setDraft] = useState<RadialMenuConfig>(value);
+    useEffect(() => setDraft(value), [value]);
+
+    const changeSlice = (index: number, binding: string | null) => {
+        // A cleared slice is removed rather than left unbound.
+        const slices = binding === null
+            ? draft.slices.filter((_, i) => i !== index)
+            : draft.slices.map((slice, i) => (i === index ? binding : slice));
+        onChange({ ...draft, slices });
+    };
+
+    return (
+        <>
+            <h4 style={styles.subHeading4}>{t('settings.keymap.radialMenu.title')}</h4>
+            <ButtonSelect
+                t={t}
+                allowNone
+                label={t('settings.keymap.radialMenu.button')}
+                value={draft.button}
+                onChange={(button) => onChange({ ...draft, button })}
+            />
+            {draft.button !== null && (
+                <>
+                    <div style={styles.formGroup}>
+                        <label style={{ ...styles.switchLabel, marginBottom: '10px' }}>{t('settings.keymap.radialMenu.slices')}</label>
+                        {draft.slices.map((slice, index) => (
+                            <div key={`${index}-${slice}`} style={styles.modeRow}>
+                                <span style={styles.modeName}>{index + 1}.</span>
+                                <BindingInput value={slice} placeholder={t('settings.keymap.none')} onChange={(binding) => changeSlice(index, binding)} />
+                                <button style={styles.smallButton} onClick={() => changeSlice(index, null)} title={t('settings.keymap.radialMenu.remove')}>✕</button>
+                            </div>
+                        ))}
+                        <button style={styles.smallButton} onClick={() => setDraft({ ...draft, slices: [...draft.slices, ''] })}>{t('settings.keymap.radialMenu.add')}</button>
+                    </div>
+                    <Slider
+                        label={t('settings.keymap.radialMenu.touchpadDeadzone')}
+                        min={0} max={0.95} step={0.05} value={draft.touchpad_deadzone}
+                        onChange={(v) => setDraft({ ...draft, touchpad_deadzone: v })}
+                        onAfterChange={() => onChange(draft)}
+                        precision={2}
+                    />
+                    <Slider
+                        label={t('settings.keymap.radialMenu.tiltDeadzone')}
+                        min={1} max={45} step={1} value={draft.tilt_deadzone}
+                        onChange={(v) => setDraft({ ...draft, tilt_deadzone: v })}
+                        onAfterChange={() => onChange(draft)}
+                        precision={0}
+                    />
+                </>
+            )}
+        </>
+    );
+};
+
+const ChordTableEditor: React.FC<{
+  chords: ChordBinding[];
+  onChange: (chords: ChordBinding[]) => void;
+  t: TFunction;
+}> = ({ chords, onChange, t }) => {
+    const [draft, setDraft] = useState<ChordBinding[]>(chords);
+    useEffect(() => setDraft(chords), [chords]);
+
+    // Rows are only saved once every chord has keys and an output, so a new row can be filled in first.
+    const update = (updated: ChordBinding[]) => {
+        setDraft(updated);
+        if (updated.every((chord) => chord.keys.length > 0 && chord.output)) onChange(updated);
+    };
+    const toggleKey = (index: number, key: ChordKey) => {
+        const keys = draft[index].keys.includes(key)
+            ? draft[index].keys.filter((k) => k !== key)
+            : ALL_CHORD_KEYS.filter((k) => k === key || draft[index].keys.includes(k));
+        update(draft.map((chord, i) => (i === index ? { ...chord, keys } : chord)));
+    };
+
+    return (
+        <>
+            <h4 style={styles.subHeading4}>{t('settings.keymap.chords.title')}</h4>
+            <table style={styles.chordTable}>
+                <thead>
+                    <tr>
+                        {ALL_CHORD_KEYS.map((key) => (
+                            <th key={key} style={styles.chordCell} title={t(`settings.keymap.chords.keys.${key}`)}>{t(`settings.keymap.chords.short.${key}`)}</th>
+                        ))}
+                        <th style={styles.chordCell}>{t('settings.keymap.chords.output')}</th>
+                        <th style={styles.chordCell} />
+                    </tr>
+                </thead>
+                <tbody>
+                    {draft.map((chord, index) => (
+                        <tr key={index}>
+                            {ALL_CHORD_KEYS.map((key) => (
+                                <td key={key} style={styles.chordCell}>
+                                    <input type="checkbox" checked={chord.keys.includes(key)} onChange={() => toggleKey(index, key)} />
+                                </td>
+                            ))}
+                            <td style={styles.chordCell}>
+                                <BindingInput
+                                    value={chord.output || null}
+                                    placeholder={t('settings.keymap.none')}
+                                    onChange={(output) => update(draft.map((c, i) => (i === index ? { ...c, output: output ?? '' } : c)))}
+                                />
+                            </td>
+                            <td style={styles.chordCell}>
+                                <button style={styles.smallButton} onClick={() => update(draft.filter((_, i) => i !== index))} title={t('settings.keymap.chords.remove')}>✕</button>
+                            </td>
+                        </tr>
+                    ))}
+                </tbody>
+            </table>
+            <button style={styles.smallButton} onClick={() => setDraft([...draft, { keys: [], output: '' }])}>{t('settings.keymap.chords.add')}</button>
+        </>
+    );
+};
+
 // --- Main Settings Component ---
 const Settings: React.FC<SettingsProps> = ({ onBack }) => {
   const { t, i18n } = useTranslation();
@@ -176,6 +911,8 @@ const Settings: React.FC<SettingsProps> = ({ onBack }) => {
   const [gyroCalibrationStatus, setGyroCalibrationStatus] = useState<CalibrationStatus>('idle');
   const [magCalibrationStep, setMagCalibrationStep] = useState('');
   const [gyroCalibrationStep, setGyroCalibrationStep] = useState('');
+  const [screenCalibrationStatus, setScreenCalibrationStatus] = useState<CalibrationStatus>('idle');
+  const [screenCalibrationStep, setScreenCalibrationStep] = useState('');
   const [controllerConfig, setControllerConfig] = useState<ControllerConfig | null>(null);
   const [mouseConfig, setMouseConfig] = useState<MouseConfig | null>(null);
   const [keymapConfig, setKeymapConfig] = useState<KeymapConfig | null>(null);
@@ -209,9 +946,7 @@ const Settings: React.FC<SettingsProps> = ({ onBack }) => {
     }
   };
 
-  const handleControllerConfigChange = useCallback((field: string, value: any) => {
-    if (!controllerConfig) return;
-    const newConfig = { ...controllerConfig, [field]: parseFloat(value) };
+  const saveControllerConfig = (newConfig: ControllerConfig) => {
     setControllerConfig(newConfig);
     invoke('set_controller_config', { config: newConfig })
         .then(() => showToast(t('settings.toast.controllerSaved'), 'success'))
@@ -219,6 +954,16 @@ const Settings: React.FC<SettingsProps> = ({ onBack }) => {
             showToast(t('settings.toast.saveFailed'), 'error');
             console.error('Failed to save controller config:', err);
         });
+  };
+
+  const handleControllerConfigChange = useCallback((field: string, value: any) => {
+    if (!controllerConfig) return;
+    saveControllerConfig({ ...controllerConfig, [field]: parseFloat(value) });
+  }, [controllerConfig]);
+
+  const handleTremorSuppressionChange = useCallback((value: TremorSuppression) => {
+    if (!controllerConfig) return;
+    saveControllerConfig({ ...controllerConfig, tremor_suppression: value });
   }, [controllerConfig]);
 
   const handleMouseConfigChange = useCallback((field: keyof MouseConfig, value: any) => {
@@ -233,9 +978,7 @@ const Settings: React.FC<SettingsProps> = ({ onBack }) => {
         });
   }, [mouseConfig]);
 
-  const handleKeymapChange = useCallback((key: keyof KeymapConfig, value: string | null) => {
-    if (!keymapConfig) return;
-    const newConfig = { ...keymapConfig, [key]: value };
+  const saveKeymapConfig = (newConfig: KeymapConfig) => {
     setKeymapConfig(newConfig);
     invoke('set_keymap_config', { config: newConfig })
         .then(() => showToast(t('settings.toast.keymapSaved'), 'success'))
@@ -243,12 +986,22 @@ const Settings: React.FC<SettingsProps> = ({ onBack }) => {
             showToast(t('settings.toast.saveFailed'), 'error');
             console.error('Failed to save keymap config:', err);
         });
+  };
+
+  const handleKeymapChange = useCallback((key: BindingKey, value: string | null) => {
+    if (!keymapConfig) return;
+    saveKeymapConfig({ ...keymapConfig, [key]: value });
+  }, [keymapConfig]);
+
+  const handleKeymapFieldChange = useCallback(<K extends keyof KeymapConfig>(field: K, value: KeymapConfig[K]) => {
+    if (!keymapConfig) return;
+    saveKeymapConfig({ ...keymapConfig, [field]: value });
   }, [keymapConfig]);
 
   useEffect(() => {
     const handleCapture = (keyString: string | null) => {
         if (!capturingKeyFor) return;
-        handleKeymapChange(capturingKeyFor as keyof KeymapConfig, keyString);
+        handleKeymapChange(capturingKeyFor as BindingKey, keyString);
         setCapturingKeyFor(null);
     };
 
@@ -289,6 +1042,8 @@ const Settings: React.FC<SettingsProps> = ({ onBack }) => {
             case 0: buttonName = 'Left'; break;
             case 1: buttonName = 'Middle'; break;
             case 2: buttonName = 'Right'; break;
+            case 3: buttonName = 'Mouse back'; break;
+            case 4: buttonName = 'Mouse forward'; break;
         }
 
         if (buttonName) {
@@ -296,17 +1051,30 @@ const Settings: React.FC<SettingsProps> = ({ onBack }) => {
         }
     };
 
+    const handleWheel = (event: WheelEvent) => {
+        event.preventDefault();
+        event.stopPropagation();
+
+        if (Math.abs(event.deltaY) >= Math.abs(event.deltaX)) {
+            handleCapture(event.deltaY < 0 ? 'Scroll up' : 'Scroll down');
+        } else {
+            handleCapture(event.deltaX < 0 ? 'Scroll left' : 'Scroll right');
+        }
+    };
+
     const preventDefault = (e: Event) => e.preventDefault();
 
     if (capturingKeyFor) {
         window.addEventListener('keydown', handleKeyDown, true);
         window.addEventListener('mousedown', handleMouseDown, true);
+        window.addEventListener('wheel', handleWheel, { capture: true, passive: false });
         window.addEventListener('contextmenu', preventDefault, true);
     } 
 
     return () => {
         window.removeEventListener('keydown', handleKeyDown, true);
         window.removeEventListener('mousedown', handleMouseDown, true);
+        window.removeEventListener('wheel', handleWheel, true);
         window.removeEventListener('contextmenu', preventDefault, true);
     };
   }, [capturingKeyFor, handleKeymapChange]);
@@ -314,6 +1082,10 @@ const Settings: React.FC<SettingsProps> = ({ onBack }) => {
   useEffect(() => {
     const unlistenMagStep = listen<string>('mag-calibration-step', (event) => setMagCalibrationStep(event.payload));
     const unlistenGyroStep = listen<string>('gyro-calibration-step', (event) => setGyroCalibrationStep(event.payload));
+    const unlistenScreenStep = listen<string>('screen-calibration-step', (event) => setScreenCalibrationStep(event.payload));
+    const unlistenModeChanged = listen<MouseConfig['mode']>('mouse-mode-changed', (event) => {
+        setMouseConfig(prev => prev ? { ...prev, mode: event.payload } : prev);
+    });
     const unlistenMagFinished = listen<boolean>('mag-calibration-finished', (event) => {
         setMagCalibrationStatus(event.payload ? 'success' : 'failed');
         invoke<ControllerConfig>('get_controller_config').then(setControllerConfig);
@@ -322,6 +1094,10 @@ const Settings: React.FC<SettingsProps> = ({ onBack }) => {
         setGyroCalibrationStatus(event.payload ? 'success' : 'failed');
         invoke<ControllerConfig>('get_controller_config').then(setControllerConfig);
     });
+    const unlistenScreenFinished = listen<boolean>('screen-calibration-finished', (event) => {
+        setScreenCalibrationStatus(event.payload ? 'success' : 'failed');
+        invoke<MouseConfig>('get_mouse_config').then(setMouseConfig);
+    });
 
     if (controllerConfig === null) {
         invoke<ControllerConfig>('get_controller_config').then(setControllerConfig).catch(console.error);
@@ -336,8 +1112,11 @@ const Settings: React.FC<SettingsProps> = ({ onBack }) => {
     return () => {
       unlistenMagStep.then(f => f());
       unlistenGyroStep.then(f => f());
+      unlistenScreenStep.then(f => f());
+      unlistenModeChanged.then(f => f());
       unlistenMagFinished.then(f => f());
       unlistenGyroFinished.then(f => f());
+      unlistenScreenFinished.then(f => f());
     };
   }, [magCalibrationStatus]);
 
@@ -365,6 +1144,17 @@ const Settings: React.FC<SettingsProps> = ({ onBack }) => {
     }
   };
 
+  const handleStartScreenCalibration = async () => {
+    try {
+      setScreenCalibrationStatus('calibrating');
+      setScreenCalibrationStep('settings.calibration.screen.steps.top_left');
+      await invoke('start_screen_calibration');
+    } catch (error) {
+      console.error('Failed to start screen calibration:', error);
+      setScreenCalibrationStatus('failed');
+    }
+  };
+
   const handleResetControllerConfig = async () => {
     try {
       const config = await invoke<ControllerConfig>('reset_controller_config');
@@ -398,6 +1188,12 @@ const Settings: React.FC<SettingsProps> = ({ onBack }) => {
     }
   };
 
+  const bindingLabel = (value: string | null) => {
+    const action = MAPPER_ACTIONS.find((a) => a.binding.toLowerCase() === value?.toLowerCase());
+    if (action) return t(`settings.keymap.actions.${action.labelKey}`);
+    return value || t('settings.keymap.none');
+  };
+
   const renderContent = () => {
     switch (activeMenu) {
       case 'calibration':
@@ -409,6 +1205,7 @@ const Settings: React.FC<SettingsProps> = ({ onBack }) => {
             <div style={styles.cardsContainer}>
                 <CalibrationCard t={t} title={t('settings.calibration.mag.title')} description={t('settings.calibration.mag.description')} status={magCalibrationStatus} calibrationStep={magCalibrationStatus === 'calibrating' ? t(magCalibrationStep) : undefined} onStart={handleStartMagCalibration} />
                 <CalibrationCard t={t} title={t('settings.calibration.gyro.title')} description={t('settings.calibration.gyro.description')} status={gyroCalibrationStatus} calibrationStep={gyroCalibrationStatus === 'calibrating' ? t(gyroCalibrationStep) : undefined} onStart={handleStartGyroCalibration} />
+                <CalibrationCard t={t} title={t('settings.calibration.screen.title')} description={t('settings.calibration.screen.description')} status={screenCalibrationStatus} calibrationStep={screenCalibrationStatus === 'calibrating' ? t(screenCalibrationStep) : undefined} onStart={handleStartScreenCalibration} />
 
                 <div style={styles.card}>
                   <h4 style={styles.subHeading4}>{t('settings.calibration.gyroData')}</h4>
@@ -464,6 +1261,11 @@ const Settings: React.FC<SettingsProps> = ({ onBack }) => {
                     onAfterChange={() => handleControllerConfigChange('local_earth_mag_field', controllerConfig.local_earth_mag_field)}
                     precision={0}
                 />
+                <TremorSuppressionEditor
+                    t={t}
+                    value={controllerConfig.tremor_suppression}
+                    onChange={handleTremorSuppressionChange}
+                />
                 <div style={styles.resetButtonContainer}>
                     <button 
                         onClick={handleResetControllerConfig} 
@@ -482,10 +1284,18 @@ const Settings: React.FC<SettingsProps> = ({ onBack }) => {
                 <div style={styles.subHeadingContainer}>
                     <h3 style={styles.subHeading}>{t('settings.mouse.title')}</h3>
                 </div>
-                <Switch
-                    label={t('settings.mouse.enableAirMouse')}
-                    checked={mouseConfig.mode === 'AirMouse'}
-                    onChange={(isChecked) => handleMouseConfigChange('mode', isChecked ? 'AirMouse' : 'Touchpad')}
+                <div style={styles.formGroupRow}>
+                    <label style={styles.switchLabel}>{t('settings.mouse.mode')}</label>
+                    <select style={styles.select} value={mouseConfig.mode} onChange={(e) => handleMouseConfigChange('mode', e.target.value as MouseMode)}>
+                        {ALL_MOUSE_MODES.map((mode) => (
+                            <option key={mode} value={mode}>{t(`settings.mouse.modes.${mode}`)}</option>
+                        ))}
+                    </select>
+                </div>
+                <EnabledModesEditor
+                    t={t}
+                    modes={mouseConfig.enabled_modes}
+                    onChange={(modes) => handleMouseConfigChange('enabled_modes', modes)}
                 />
                 <Slider
                     label={t('settings.mouse.touchpadSensitivity')}
@@ -508,6 +1318,16 @@ const Settings: React.FC<SettingsProps> = ({ onBack }) => {
                     onAfterChange={() => handleMouseConfigChange('touchpad_acceleration_threshold', mouseConfig.touchpad_acceleration_threshold)}
                     precision={4}
                 />
+                <Switch
+                    label={t('settings.mouse.touchpadAbsolute')}
+                    checked={mouseConfig.touchpad_absolute}
+                    onChange={(isChecked) => handleMouseConfigChange('touchpad_absolute', isChecked)}
+                />
+                <MappingRegionEditor
+                    t={t}
+                    region={mouseConfig.mapping_region}
+                    onChange={(region) => handleMouseConfigChange('mapping_region', region)}
+                />
                 <Slider
                     label={t('settings.mouse.airMouseFov')}
                     min={10} max={180} step={1} value={mouseConfig.air_mouse_fov}
@@ -522,6 +1342,69 @@ const Settings: React.FC<SettingsProps> = ({ onBack }) => {
                     onAfterChange={() => handleMouseConfigChange('air_mouse_activation_threshold', mouseConfig.air_mouse_activation_threshold)}
                     precision={1}
                 />
+                <Slider
+                    label={t('settings.mouse.clickStabilization')}
+                    min={0} max={500} step={10} value={mouseConfig.click_stabilization_ms}
+                    onChange={(v) => setMouseConfig({...mouseConfig, click_stabilization_ms: v})}
+                    onAfterChange={() => handleMouseConfigChange('click_stabilization_ms', mouseConfig.click_stabilization_ms)}
+                    precision={0}
+                />
+                <Switch
+                    label={t('settings.mouse.dwellClick')}
+                    checked={mouseConfig.dwell_click_enabled}
+                    onChange={(isChecked) => handleMouseConfigChange('dwell_click_enabled', isChecked)}
+                />
+                <Slider
+                    label={t('settings.mouse.dwellClickTime')}
+                    min={200} max={3000} step={50} value={mouseConfig.dwell_click_time_ms}
+                    onChange={(v) => setMouseConfig({...mouseConfig, dwell_click_time_ms: v})}
+                    onAfterChange={() => handleMouseConfigChange('dwell_click_time_ms', mouseConfig.dwell_click_time_ms)}
+                    precision={0}
+                />
+                <Switch
+                    label={t('settings.mouse.adaptiveSmoothing')}
+                    checked={mouseConfig.adaptive_smoothing}
+                    onChange={(isChecked) => handleMouseConfigChange('adaptive_smoothing', isChecked)}
+                />
+                <Slider
+                    label={t('settings.mouse.smoothingMinCutoff')}
+                    min={0.1} max={10} step={0.1} value={mouseConfig.smoothing_min_cutoff}
+                    onChange={(v) => setMouseConfig({...mouseConfig, smoothing_min_cutoff: v})}
+                    onAfterChange={() => handleMouseConfigChange('smoothing_min_cutoff', mouseConfig.smoothing_min_cutoff)}
+                    precision={1}
+                />
+                <Slider
+                    label={t('settings.mouse.smoothingBeta')}
+                    min={0} max={0.1} step={0.001} value={mouseConfig.smoothing_beta}
+                    onChange={(v) => setMouseConfig({...mouseConfig, smoothing_beta: v})}
+                    onAfterChange={() => handleMouseConfigChange('smoothing_beta', mouseConfig.smoothing_beta)}
+                    precision={3}
+                />
+                <Switch
+                    label={t('settings.mouse.uinputBackend')}
+                    checked={mouseConfig.input_backend === 'Uinput'}
+                    onChange={(isChecked) => handleMouseConfigChange('input_backend', isChecked ? 'Uinput' : 'Enigo')}
+                />
+                <Switch
+                    label={t('settings.mouse.gamepadOutput')}
+                    checked={mouseConfig.output_mode === 'Gamepad'}
+                    onChange={(isChecked) => handleMouseConfigChange('output_mode', isChecked ? 'Gamepad' : 'Mouse')}
+                />
+                <TiltKeysEditor
+                    t={t}
+                    value={mouseConfig.tilt_keys}
+                    onChange={(tiltKeys) => handleMouseConfigChange('tilt_keys', tiltKeys)}
+                />
+                <GyroAimEditor
+                    t={t}
+                    value={mouseConfig.gyro_aim}
+                    onChange={(gyroAim) => handleMouseConfigChange('gyro_aim', gyroAim)}
+                />
+                <TiltScrollEditor
+                    t={t}
+                    value={mouseConfig.tilt_scroll}
+                    onChange={(tiltScroll) => handleMouseConfigChange('tilt_scroll', tiltScroll)}
+                />
                 <div style={styles.resetButtonContainer}>
                     <button 
                         onClick={handleResetMouseConfig} 
@@ -541,17 +1424,51 @@ const Settings: React.FC<SettingsProps> = ({ onBack }) => {
                     <h3 style={styles.subHeading}>{t('settings.keymap.title')}</h3>
                 </div>
                 <h4 style={styles.subHeading4}>{t('settings.keymap.description')}</h4>
-                {Object.entries(keymapConfig).map(([key, value]) => (
+                {BINDING_KEYS.map((key) => (
                 <div style={styles.formGroupRow} key={key}>
                   <label style={styles.keymapLabel}>{t(`settings.keymap.keys.${key}`)}</label>
-                  <button 
-                    onClick={() => setCapturingKeyFor(key)}
-                    style={capturingKeyFor === key ? styles.keymapButtonCapturing : styles.keymapButton}
-                  >
-                    {capturingKeyFor === key ? t('settings.keymap.capturing') : (value || t('settings.keymap.none'))}
-                  </button>
+                  <div style={styles.keymapControls}>
+                    <select style={styles.select} value="" onChange={(e) => handleKeymapChange(key, e.target.value)}>
+                      <option value="" disabled>{t('settings.keymap.actions.title')}</option>
+                      {MAPPER_ACTIONS.map((action) => (
+                        <option key={action.binding} value={action.binding}>{t(`settings.keymap.actions.${action.labelKey}`)}</option>
+                      ))}
+                    </select>
+                    <button 
+                      onClick={() => setCapturingKeyFor(key)}
+                      style={capturingKeyFor === key ? styles.keymapButtonCapturing : styles.keymapButton}
+                    >
+                      {capturingKeyFor === key ? t('settings.keymap.capturing') : bindingLabel(keymapConfig[key])}
+                    </button>
+                  </div>
                 </div>
               ))}
+              <ModeCycleEditor
+                t={t}
+                value={keymapConfig.mode_cycle}
+                onChange={(modeCycle) => handleKeymapFieldChange('mode_cycle', modeCycle)}
+              />
+              <PauseChordEditor
+                t={t}
+                chord={keymapConfig.pause_chord}
+                holdMs={keymapConfig.pause_hold_ms}
+                onChange={(chord, holdMs) => saveKeymapConfig({ ...keymapConfig, pause_chord: chord, pause_hold_ms: holdMs })}
+              />
+              <TiltKeyBindingsEditor
+                t={t}
+                value={keymapConfig.tilt_keys}
+                onChange={(bindings) => handleKeymapFieldChange('tilt_keys', bindings)}
+              />
+              <RadialMenuEditor
+                t={t}
+                value={keymapConfig.radial_menu}
+                onChange={(radialMenu) => handleKeymapFieldChange('radial_menu', radialMenu)}
+              />
+              <ChordTableEditor
+                t={t}
+                chords={keymapConfig.chords}
+                onChange={(chords) => handleKeymapFieldChange('chords', chords)}
+              />
               <div style={styles.resetButtonContainer}>
                   <button 
                     onClick={handleResetKeymapConfig} 
@@ -656,6 +1573,14 @@ const styles: { [key: string]: React.CSSProperties } = {
     formGroup: { marginBottom: '20px', display: 'flex', flexDirection: 'column', alignItems: 'flex-start' },
     formGroupRow: { marginBottom: '20px', display: 'flex', alignItems: 'center', justifyContent: 'space-between' },
     input: { width: '100%', padding: '8px', backgroundColor: '#333', border: '1px solid #555', borderRadius: '4px', color: '#fff', marginTop: '5px', boxSizing: 'border-box' },
+    modeRow: { display: 'flex', alignItems: 'center', gap: '8px', marginBottom: '8px', width: '100%' },
+    modeName: { flex: 1, color: '#eee' },
+    smallButton: { backgroundColor: '#333', color: '#fff', border: '1px solid #555', padding: '4px 10px', borderRadius: '5px', cursor: 'pointer' },
+    regionFields: { display: 'grid', gridTemplateColumns: 'repeat(4, 1fr)', gap: '10px', width: '100%' },
+    chordTable: { width: '100%', borderCollapse: 'collapse', marginBottom: '10px', color: '#eee' },
+    chordCell: { padding: '4px', textAlign: 'center', borderBottom: '1px solid #444' },
+    checkboxLabel: { display: 'flex', alignItems: 'center', gap: '6px', color: '#eee' },
+    regionField: { display: 'flex', flexDirection: 'column', color: '#eee', fontSize: '0.9rem' },
     matrixContainer: { display: 'grid', gridTemplateColumns: 'repeat(3, 1fr)', gap: '5px', backgroundColor: '#1e1e1e', padding: '10px', borderRadius: '4px' },
     matrixCell: { backgroundColor: '#2a2a2a', padding: '5px', textAlign: 'center', borderRadius: '4px' },
     vectorContainer: { display: 'flex', gap: '10px', backgroundColor: '#1e1e1e', padding: '10px', borderRadius: '4px' },
@@ -722,6 +1647,11 @@ const styles: { [key: string]: React.CSSProperties } = {
         color: '#eee',
         fontSize: '1rem',
     },
+    keymapControls: {
+        display: 'flex',
+        alignItems: 'center',
+        gap: '10px',
+    },
     keymapButton: {
         backgroundColor: '#333',
         color: '#fff',
@@ -1,5 +1,7 @@
-import React from 'react';
+import React, { useEffect, useState } from 'react';
 import { useTranslation } from 'react-i18next';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
 import { AppView } from '../App';
 
 interface StatusBarProps {
@@ -12,6 +14,20 @@ interface StatusBarProps {
 
 const StatusBar: React.FC<StatusBarProps> = ({ isConnected, deviceName, showLogs, setShowLogs, onViewChange }) => {
   const { t } = useTranslation();
+  const [isPaused, setIsPaused] = useState(false);
+
+  useEffect(() => {
+    invoke<boolean>('get_input_paused').then(setIsPaused).catch(console.error);
+    const unlistenPaused = listen<boolean>('input-paused', (event) => setIsPaused(event.payload));
+    return () => {
+      unlistenPaused.then(unlisten => unlisten());
+    };
+  }, []);
+
+  const handlePauseClick = () => {
+    invoke('set_input_paused', { paused: !isPaused }).catch(console.error);
+  };
+
   const handleSettingsClick = () => {
     if (isConnected) {
       onViewChange('settings');
@@ -32,6 +48,13 @@ const StatusBar: React.FC<StatusBarProps> = ({ isConnected, deviceName, showLogs
       </div>
       
       <div className="status-right">
+        <div
+          className="log-toggle-link"
+          onClick={handlePauseClick}
+          style={{ marginRight: '1rem' }}
+        >
+          {isPaused ? t('statusBar.resumeInput') : t('statusBar.pauseInput')}
+        </div>
         <div 
           className="log-toggle-link" 
           onClick={() => setShowLogs(!showLogs)}
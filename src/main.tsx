@@ -1,10 +1,15 @@
 import React from "react";
 import ReactDOM from "react-dom/client";
+import { getCurrentWindow } from "@tauri-apps/api/window";
 import App from "./App";
+import Overlay from "./components/Overlay";
 import "./i18n";
 
+// The overlay window loads the same page but only draws the on-screen feedback.
+const isOverlay = getCurrentWindow().label === "overlay";
+
 ReactDOM.createRoot(document.getElementById("root") as HTMLElement).render(
   <React.StrictMode>
-    <App />
+    {isOverlay ? <Overlay /> : <App />}
   </React.StrictMode>,
 );